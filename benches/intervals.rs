@@ -0,0 +1,52 @@
+//! Timing of interval generation, run with `cargo bench`.
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use chrono_intervals::{Grouping, IntervalGenerator};
+
+const NUM_RUNS: u32 = 20;
+
+fn time_runs<T>(name: &str, grouping: Grouping, begin: DateTime<T>, end: DateTime<T>)
+where
+    T: TimeZone,
+{
+    let inter_gen = IntervalGenerator::new().with_grouping(grouping);
+    let mut fastest = Duration::MAX;
+    let mut num_intervals = 0;
+    for _ in 0..NUM_RUNS {
+        let start = Instant::now();
+        num_intervals = inter_gen.get_intervals(begin.clone(), end.clone()).len();
+        fastest = fastest.min(start.elapsed());
+    }
+    println!(
+        "{}: {} intervals in {:?} (fastest of {} runs)",
+        name, num_intervals, fastest, NUM_RUNS
+    );
+}
+
+fn main() {
+    let begin = Utc.ymd(2000, 1, 1).and_hms(0, 0, 0);
+    let end = Utc.ymd(2010, 1, 1).and_hms(0, 0, 0);
+    time_runs(
+        "quarter hours over 10 years in UTC",
+        Grouping::PerQuarterHour,
+        begin,
+        end,
+    );
+
+    let offset = FixedOffset::west(7 * 3600);
+    time_runs(
+        "quarter hours over 10 years in PDT",
+        Grouping::PerQuarterHour,
+        begin.with_timezone(&offset),
+        end.with_timezone(&offset),
+    );
+
+    let end = Utc.ymd(2020, 1, 1).and_hms(0, 0, 0);
+    time_runs(
+        "months over 20 years in PDT",
+        Grouping::PerMonth,
+        begin.with_timezone(&offset),
+        end.with_timezone(&offset),
+    );
+}
@@ -0,0 +1,18 @@
+//! Anchoring of time intervals.
+//!
+//! Specify where the first time interval starts. With `AnchorMode::Boundary`
+//! (default), intervals are aligned to the calendar boundaries of the
+//! grouping, e.g. midnight for `Grouping::PerDay`. With `AnchorMode::Begin`,
+//! the first interval starts exactly at `begin` and every subsequent one
+//! starts one grouping period later, e.g. 24h for `Grouping::PerDay` or one
+//! calendar month for `Grouping::PerMonth`. With `AnchorMode::At`, the
+//! intervals are aligned so that one of them starts at the given time, e.g. to
+//! align `Grouping::PerTwoWeeks` to the start of a sprint.
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnchorMode {
+    Boundary,
+    Begin,
+    At(DateTime<Utc>),
+}
@@ -0,0 +1,44 @@
+//! Plain configuration of the interval generator.
+use chrono::Duration;
+
+use crate::{Grouping, IntervalGenerator};
+
+/// Configuration of an [`IntervalGenerator`] with public fields.
+///
+/// This allows to construct a generator from configuration loaded e.g. from a
+/// file or environment variables.
+pub struct IntervalConfig {
+    pub grouping: Grouping,
+    pub offset_west_seconds: i32,
+    pub end_precision_nanos: i64,
+    pub extend_begin: bool,
+    pub extend_end: bool,
+}
+
+impl Default for IntervalConfig {
+    fn default() -> Self {
+        IntervalConfig {
+            grouping: Grouping::PerDay,
+            offset_west_seconds: 0,
+            end_precision_nanos: 1_000_000,
+            extend_begin: true,
+            extend_end: true,
+        }
+    }
+}
+
+impl From<IntervalConfig> for IntervalGenerator {
+    fn from(config: IntervalConfig) -> Self {
+        let mut inter_gen = IntervalGenerator::new()
+            .with_grouping(config.grouping)
+            .with_offset_west_secs(config.offset_west_seconds)
+            .with_precision(Duration::nanoseconds(config.end_precision_nanos));
+        if !config.extend_begin {
+            inter_gen = inter_gen.without_extended_begin();
+        }
+        if !config.extend_end {
+            inter_gen = inter_gen.without_extended_end();
+        }
+        inter_gen
+    }
+}
@@ -0,0 +1,57 @@
+//! Parsing of ISO 8601 durations.
+use chrono::Duration;
+
+/// Parse a subset of ISO 8601 durations, e.g. `PT0.001S` or `P1DT12H`.
+///
+/// Only days, hours, minutes and seconds are supported since years, months
+/// and weeks have no fixed length. Only the seconds may have a fraction of up
+/// to nanosecond precision. Returns `None` if the duration is malformed.
+pub(crate) fn parse_iso_duration(s: &str) -> Option<Duration> {
+    let s = s.strip_prefix('P')?;
+    let (date_part, time_part) = match s.split_once('T') {
+        Some((date_part, time_part)) if !time_part.is_empty() => (date_part, Some(time_part)),
+        Some(_) => return None,
+        None => (s, None),
+    };
+    if date_part.is_empty() && time_part.is_none() {
+        return None;
+    }
+
+    let mut duration = Duration::zero();
+    if !date_part.is_empty() {
+        let days = date_part.strip_suffix('D')?;
+        duration += Duration::days(parse_digits(days)?.into());
+    }
+
+    let mut rest = time_part.unwrap_or_default();
+    for (designator, unit) in [('H', Duration::hours(1)), ('M', Duration::minutes(1))] {
+        if let Some((value, after)) = rest.split_once(designator) {
+            duration += unit * parse_digits(value)?;
+            rest = after;
+        }
+    }
+    if !rest.is_empty() {
+        let seconds = rest.strip_suffix('S')?;
+        let (whole, fraction) = match seconds.split_once('.') {
+            Some((whole, fraction)) if !fraction.is_empty() && fraction.len() <= 9 => {
+                (whole, fraction)
+            }
+            Some(_) => return None,
+            None => (seconds, ""),
+        };
+        let nanos = parse_digits(&format!("{:0<9}", fraction))?;
+        duration += Duration::seconds(parse_digits(whole)?.into());
+        duration += Duration::nanoseconds(nanos.into());
+    }
+
+    Some(duration)
+}
+
+/// Parse a non-negative number without sign, limited to `i32` so that the
+/// sum of all parts cannot overflow a `Duration`.
+fn parse_digits(s: &str) -> Option<i32> {
+    match !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        true => s.parse().ok(),
+        false => None,
+    }
+}
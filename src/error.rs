@@ -0,0 +1,56 @@
+//! Errors of interval computations.
+use std::fmt;
+
+/// Error when computing intervals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntervalError {
+    /// The label does not match the label format of the grouping.
+    InvalidLabel(String),
+    /// The string is not the identifier of a grouping.
+    InvalidGrouping(String),
+    /// The string is not an ISO 8601 duration supported by the crate.
+    InvalidDuration(String),
+    /// The beginning of the range is after its end.
+    ReversedRange,
+    /// The end precision is not shorter than the intervals of the grouping.
+    PrecisionTooLarge,
+    /// The end precision in nanoseconds is not positive.
+    NonPositivePrecision(i64),
+    /// Both a fixed offset and a timezone for wall-clock days are set.
+    ConflictingTimezones,
+    /// The offset in seconds is not less than a day in either direction.
+    InvalidOffset(i32),
+}
+
+impl fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntervalError::InvalidLabel(label) => write!(f, "invalid interval label {:?}", label),
+            IntervalError::InvalidGrouping(grouping) => {
+                write!(f, "invalid grouping {:?}", grouping)
+            }
+            IntervalError::InvalidDuration(duration) => {
+                write!(f, "invalid ISO 8601 duration {:?}", duration)
+            }
+            IntervalError::ReversedRange => write!(f, "begin of the range is after its end"),
+            IntervalError::PrecisionTooLarge => write!(
+                f,
+                "end precision is not shorter than the intervals of the grouping"
+            ),
+            IntervalError::NonPositivePrecision(nanos) => {
+                write!(f, "end precision must be positive, got {}ns", nanos)
+            }
+            IntervalError::ConflictingTimezones => write!(
+                f,
+                "both a fixed offset and a timezone for wall-clock days are set, pick one of them"
+            ),
+            IntervalError::InvalidOffset(offset_west_seconds) => write!(
+                f,
+                "offset of {}s is out of range, it must be less than a day",
+                offset_west_seconds
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {}
@@ -0,0 +1,12 @@
+//! Labeling of fiscal years.
+//!
+//! With a fiscal year start set, a yearly interval spans two calendar years,
+//! e.g. April 1st 2022 to March 31st 2023. With `FiscalYearLabel::StartYear`
+//! (default), this fiscal year is labeled by the year it starts in, `FY2022`.
+//! With `FiscalYearLabel::EndYear`, it is labeled by the year it ends in,
+//! `FY2023`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FiscalYearLabel {
+    StartYear,
+    EndYear,
+}
@@ -1,40 +1,252 @@
 //! Time interval generator.
-use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
+    panic::RefUnwindSafe,
+    sync::{mpsc::Sender, Arc},
+};
 
-use crate::{intervals_impl::get_intervals_impl, Grouping, TimeInterval};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc, Weekday,
+};
+
+use crate::{
+    duration::parse_iso_duration,
+    intervals_impl::{
+        get_first_interval_impl, get_last_interval_impl, get_n_intervals_impl,
+        get_n_strategy_intervals_impl, get_next_interval_impl, get_previous_interval_impl,
+        get_rules, EqualLengthMonth, IntervalIter, SlidingWindow, WallClockDay,
+    },
+    labels::{format_label, LabeledInterval},
+    offsets::{checked_offset_west, clamped_offset_west},
+    precision::{DynamicPrecision, Precision},
+    search::{cmp_by_start, find_interval_containing},
+    AnchorMode, FiscalYearLabel, Grouping, GroupingStrategy, Interval, IntervalError, Intervals,
+    NeighboredInterval, TimeInterval, WeekdayNumbering,
+};
 
 /// Generator for time intervals.
-pub struct IntervalGenerator {
+///
+/// The precision is a [`DynamicPrecision`] set at runtime by default. With
+/// [`IntervalGenerator::with_typed_precision`], it is fixed by the type `P`
+/// instead, see [`Precision`].
+#[derive(Clone)]
+pub struct IntervalGenerator<P = DynamicPrecision> {
     grouping: Grouping,
-    end_precision: Duration,
+    precision: P,
     local_timezone: FixedOffset,
+    offset_set: bool,
+    invalid_offset: Option<i32>,
+    wall_clock_days: bool,
     extend_begin: bool,
     extend_end: bool,
+    partial_first_week: bool,
+    clamped_ends: bool,
+    min_last_bucket: Option<Duration>,
+    anchor_mode: AnchorMode,
+    point_returns_enclosing: bool,
+    timezone_name: Option<String>,
+    label_at_end: bool,
+    subwindow: Option<(Duration, Duration)>,
+    dense: bool,
+    snap_inputs: bool,
+    fiscal_year_start: Option<(u32, u32)>,
+    fiscal_year_label: FiscalYearLabel,
+    day_start_time: Option<NaiveTime>,
+    week_start: Option<Weekday>,
+    weekday_numbering: WeekdayNumbering,
+    period_phase: Option<f64>,
+    strategy: Option<Arc<dyn GroupingStrategy>>,
 }
 
 impl IntervalGenerator {
     pub fn new() -> Self {
         IntervalGenerator {
             grouping: Grouping::PerDay,
-            end_precision: Duration::milliseconds(1),
-            local_timezone: FixedOffset::west(0),
+            precision: DynamicPrecision::default(),
+            local_timezone: clamped_offset_west(0),
+            offset_set: false,
+            invalid_offset: None,
+            wall_clock_days: false,
             extend_begin: true,
             extend_end: true,
+            partial_first_week: false,
+            clamped_ends: false,
+            min_last_bucket: None,
+            anchor_mode: AnchorMode::Boundary,
+            point_returns_enclosing: false,
+            timezone_name: None,
+            label_at_end: false,
+            subwindow: None,
+            dense: false,
+            snap_inputs: false,
+            fiscal_year_start: None,
+            fiscal_year_label: FiscalYearLabel::StartYear,
+            day_start_time: None,
+            week_start: None,
+            weekday_numbering: WeekdayNumbering::MondayZero,
+            period_phase: None,
+            strategy: None,
         }
     }
 
+    pub fn with_precision(mut self, precision: Duration) -> Self {
+        self.precision = DynamicPrecision(precision);
+        self
+    }
+
+    /// Set the precision, failing if it is too large for the grouping.
+    ///
+    /// Returns [`IntervalError::PrecisionTooLarge`] if `precision` is not
+    /// shorter than the shortest interval of the grouping since the intervals
+    /// would be empty or end before they begin.
+    pub fn try_with_precision(self, precision: Duration) -> Result<Self, IntervalError> {
+        let inter_gen = self.with_precision(precision);
+        inter_gen.check_precision()?;
+        Ok(inter_gen)
+    }
+
+    /// Set the precision from an ISO 8601 duration, e.g. `PT0.001S` for 1ms.
+    ///
+    /// Only days, hours, minutes and seconds are supported, e.g. `P1DT2H` or
+    /// `PT1M30.5S`. Returns [`IntervalError::InvalidDuration`] for other or
+    /// malformed durations.
+    pub fn with_end_precision_iso(self, precision: &str) -> Result<Self, IntervalError> {
+        let precision = parse_iso_duration(precision)
+            .ok_or_else(|| IntervalError::InvalidDuration(precision.to_owned()))?;
+        Ok(self.with_precision(precision))
+    }
+
+    /// Set the precision in nanoseconds.
+    ///
+    /// Use [`Self::try_with_end_precision_nanos`] for precisions from user
+    /// input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nanos` is not positive.
+    pub fn with_end_precision_nanos(self, nanos: i64) -> Self {
+        assert!(nanos > 0, "end precision must be positive, got {}ns", nanos);
+        self.with_precision(Duration::nanoseconds(nanos))
+    }
+
+    /// Set the precision in nanoseconds, failing if it is not positive or too
+    /// large for the grouping.
+    ///
+    /// Returns [`IntervalError::NonPositivePrecision`] if `nanos` is not
+    /// positive and [`IntervalError::PrecisionTooLarge`] like
+    /// [`Self::try_with_precision`].
+    pub fn try_with_end_precision_nanos(self, nanos: i64) -> Result<Self, IntervalError> {
+        match nanos > 0 {
+            true => self.try_with_precision(Duration::nanoseconds(nanos)),
+            false => Err(IntervalError::NonPositivePrecision(nanos)),
+        }
+    }
+
+    /// Bake the precision into the type of the generator.
+    ///
+    /// With e.g. [`Micros`](crate::Micros), the precision is known at compile
+    /// time and not stored at runtime. All other settings are kept.
+    pub fn with_typed_precision<Q: Precision>(self, precision: Q) -> IntervalGenerator<Q> {
+        IntervalGenerator {
+            grouping: self.grouping,
+            precision,
+            local_timezone: self.local_timezone,
+            offset_set: self.offset_set,
+            invalid_offset: self.invalid_offset,
+            wall_clock_days: self.wall_clock_days,
+            extend_begin: self.extend_begin,
+            extend_end: self.extend_end,
+            partial_first_week: self.partial_first_week,
+            clamped_ends: self.clamped_ends,
+            min_last_bucket: self.min_last_bucket,
+            anchor_mode: self.anchor_mode,
+            point_returns_enclosing: self.point_returns_enclosing,
+            timezone_name: self.timezone_name,
+            label_at_end: self.label_at_end,
+            subwindow: self.subwindow,
+            dense: self.dense,
+            snap_inputs: self.snap_inputs,
+            fiscal_year_start: self.fiscal_year_start,
+            fiscal_year_label: self.fiscal_year_label,
+            day_start_time: self.day_start_time,
+            week_start: self.week_start,
+            weekday_numbering: self.weekday_numbering,
+            period_phase: self.period_phase,
+            strategy: self.strategy,
+        }
+    }
+}
+
+impl<P: Precision> IntervalGenerator<P> {
     pub fn with_grouping(mut self, grouping: Grouping) -> Self {
         self.grouping = grouping;
+        self.assert_subwindow_fits();
         self
     }
 
-    pub fn with_precision(mut self, precision: Duration) -> Self {
-        self.end_precision = precision;
+    /// Group the intervals with a custom strategy instead of the grouping.
+    ///
+    /// The strategy is used for the intervals returned by
+    /// [`IntervalGenerator::get_intervals`], [`IntervalGenerator::get_n_intervals`]
+    /// and the methods based on them. The anchor mode and the partial first
+    /// week do not apply to custom strategies. Labels, neighbor intervals,
+    /// [`IntervalGenerator::previous_interval`],
+    /// [`IntervalGenerator::next_interval`] and the methods based on them as
+    /// well as the checks of the precision and the subwindow still use the
+    /// grouping.
+    pub fn with_strategy(mut self, strategy: Box<dyn GroupingStrategy>) -> Self {
+        self.strategy = Some(Arc::from(strategy));
+        self.wall_clock_days = false;
         self
     }
 
+    /// Set the grouping by its identifier, see [`Grouping::id`].
+    ///
+    /// Returns [`IntervalError::InvalidGrouping`] if `grouping` is not the
+    /// identifier of a grouping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a subwindow is set which does not fit into the intervals of
+    /// the grouping.
+    pub fn with_grouping_str(self, grouping: &str) -> Result<Self, IntervalError> {
+        Ok(self.with_grouping(grouping.parse()?))
+    }
+
+    /// Shift the interval boundaries by `offset_west_secs` towards the west.
+    ///
+    /// An offset of a day or more is clamped to just below a day and makes
+    /// [`IntervalGenerator::get_intervals_checked`] return
+    /// [`IntervalError::InvalidOffset`].
     pub fn with_offset_west_secs(mut self, offset_west_secs: i32) -> Self {
-        self.local_timezone = FixedOffset::west(offset_west_secs);
+        self.local_timezone = clamped_offset_west(offset_west_secs);
+        self.invalid_offset = checked_offset_west(offset_west_secs)
+            .err()
+            .map(|_| offset_west_secs);
+        self.offset_set = true;
+        self
+    }
+
+    /// Set a timezone name to tag labeled intervals with, e.g. an IANA name.
+    ///
+    /// The name is only used for labeling. The interval boundaries are still
+    /// computed with the offset set by
+    /// [`IntervalGenerator::with_offset_west_secs`].
+    pub fn with_timezone_name(mut self, timezone_name: impl Into<String>) -> Self {
+        self.timezone_name = Some(timezone_name.into());
+        self
+    }
+
+    /// Label intervals by the date they end on in the local timezone instead
+    /// of the date they begin on.
+    ///
+    /// The label is the end date as `YYYY-MM-DD` for all groupings, e.g.
+    /// `2022-06-30` for June 2022 with [`Grouping::PerMonth`]. Intervals
+    /// with [`Grouping::PerQuarterHour`] keep their label format, so the
+    /// label contains the minute of the end.
+    pub fn with_label_at_end(mut self) -> Self {
+        self.label_at_end = true;
         self
     }
 
@@ -54,20 +266,1518 @@ impl IntervalGenerator {
         self
     }
 
-    pub fn get_intervals<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> Vec<TimeInterval<Utc>>
+    /// Set where the first interval starts, see [`AnchorMode`].
+    ///
+    /// With [`AnchorMode::Begin`], the extension of the begin does not
+    /// apply since the first interval starts exactly at `begin`.
+    pub fn with_anchor_mode(mut self, anchor_mode: AnchorMode) -> Self {
+        self.anchor_mode = anchor_mode;
+        self
+    }
+
+    /// Align the intervals so that one of them starts at `anchor`.
+    ///
+    /// This sets the anchor mode to [`AnchorMode::At`], e.g. to align
+    /// [`Grouping::PerTwoWeeks`] to the start of a sprint.
+    pub fn with_anchor<T>(self, anchor: DateTime<T>) -> Self
+    where
+        T: TimeZone,
+    {
+        self.with_anchor_mode(AnchorMode::At(anchor.with_timezone(&Utc)))
+    }
+
+    /// Trim every interval to a subwindow of it.
+    ///
+    /// Each interval then starts `start_offset` after its boundary and ends
+    /// `length` later (minus the precision), e.g. to get only the working
+    /// hours of each day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start_offset` is negative, `length` is not positive or the
+    /// subwindow does not fit into the shortest interval of the grouping.
+    pub fn with_subwindow(mut self, start_offset: Duration, length: Duration) -> Self {
+        assert!(
+            start_offset >= Duration::zero(),
+            "subwindow offset must not be negative"
+        );
+        assert!(
+            length > Duration::zero(),
+            "subwindow length must be positive"
+        );
+        self.subwindow = Some((start_offset, length));
+        self.assert_subwindow_fits();
+        self
+    }
+
+    /// Start the intervals of [`Grouping::PerYear`] on `month`/`day` instead
+    /// of January 1st.
+    ///
+    /// The intervals then span fiscal years, e.g. from April 1st to March 31st
+    /// with `with_fiscal_year_start(4, 1)`. They are labeled `FY` followed by
+    /// the year chosen with [`IntervalGenerator::with_fiscal_year_label`].
+    /// The periods of [`Grouping::PerRetail445`] start with the fiscal year as
+    /// well. The fiscal year start has no effect with another anchor mode
+    /// than [`AnchorMode::Boundary`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month`/`day` is not a valid day in every year, e.g. for
+    /// February 29th.
+    pub fn with_fiscal_year_start(mut self, month: u32, day: u32) -> Self {
+        assert!(
+            NaiveDate::from_ymd_opt(2001, month, day).is_some(),
+            "fiscal year start must be a valid day in every year"
+        );
+        self.fiscal_year_start = Some((month, day));
+        self
+    }
+
+    /// Start the intervals of [`Grouping::PerDay`] at `time` instead of
+    /// midnight in the local timezone.
+    ///
+    /// With a start time of 09:30, a day runs from 09:30 to 09:30 on the next
+    /// day minus the end precision and is labeled by the date it starts on.
+    /// The day start time has no effect with another anchor mode than
+    /// [`AnchorMode::Boundary`].
+    pub fn with_day_start_time(mut self, time: NaiveTime) -> Self {
+        self.day_start_time = Some(time);
+        self
+    }
+
+    /// Group the intervals per calendar day in `timezone`.
+    ///
+    /// By default, a day is 24 hours long in the local timezone with a fixed
+    /// offset. With wall-clock days, a day runs from midnight to midnight in
+    /// `timezone` instead, so that it is 23 or 25 hours long when daylight
+    /// saving time starts or ends. Any timezone of `chrono` can be used, e.g.
+    /// `chrono_tz::Tz`. The days are a custom strategy, see
+    /// [`IntervalGenerator::with_strategy`].
+    ///
+    /// The days ignore the offset, so
+    /// [`IntervalGenerator::get_intervals_checked`] returns
+    /// [`IntervalError::ConflictingTimezones`] if an offset is set as well.
+    pub fn with_wall_clock_days<Tz>(self, timezone: Tz) -> Self
+    where
+        Tz: TimeZone + Send + Sync + RefUnwindSafe + 'static,
+    {
+        let mut inter_gen = self
+            .with_grouping(Grouping::PerDay)
+            .with_strategy(Box::new(WallClockDay { timezone }));
+        inter_gen.wall_clock_days = true;
+        inter_gen
+    }
+
+    /// Group the intervals into consecutive windows of exactly 28 days
+    /// starting at `begin`.
+    ///
+    /// These are **not** calendar months: The first window starts exactly at
+    /// `begin` instead of on a month boundary and every window ends 28 days
+    /// minus the end precision after its beginning, so that the windows are
+    /// comparable in length. The extension of the begin has no effect. The
+    /// windows are a custom strategy, see [`IntervalGenerator::with_strategy`].
+    pub fn with_equal_length_months(self) -> Self {
+        self.with_grouping(Grouping::PerMonth)
+            .with_strategy(Box::new(EqualLengthMonth))
+    }
+
+    /// Generate windows of length `window` starting `step` after each other
+    /// from `begin` on.
+    ///
+    /// With a `step` shorter than the `window`, the windows overlap, e.g. 7-day
+    /// windows advancing one day at a time for moving averages. The extension
+    /// of the begin has no effect. The windows are a custom strategy, see
+    /// [`IntervalGenerator::with_strategy`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` or `step` is not positive.
+    pub fn with_sliding(self, window: Duration, step: Duration) -> Self {
+        assert!(window > Duration::zero(), "sliding window must be positive");
+        assert!(step > Duration::zero(), "sliding step must be positive");
+        self.with_strategy(Box::new(SlidingWindow { window, step }))
+    }
+
+    /// Start the intervals of [`Grouping::PerWeek`] on `weekday` instead of
+    /// Monday in the local timezone.
+    ///
+    /// The week start has no effect with another anchor mode than
+    /// [`AnchorMode::Boundary`].
+    pub fn with_week_start(mut self, weekday: Weekday) -> Self {
+        self.week_start = Some(weekday);
+        self
+    }
+
+    /// Number weekdays with `numbering` in [`IntervalGenerator::weekday_index`].
+    ///
+    /// By default, Monday is day 0 like in chrono.
+    pub fn with_weekday_numbering(mut self, numbering: WeekdayNumbering) -> Self {
+        self.weekday_numbering = numbering;
+        self
+    }
+
+    /// Shift all interval boundaries by `fraction` of the period length.
+    ///
+    /// With [`Grouping::PerDay`] and a `fraction` of 0.5, days run from noon
+    /// to noon in the local timezone. The period length is the length of the
+    /// interval starting on January 1st 2001, e.g. 31 days for
+    /// [`Grouping::PerMonth`]. The phase is applied on top of the fiscal year,
+    /// day or week start and has no effect with another anchor mode than
+    /// [`AnchorMode::Boundary`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fraction` is not in the range from 0.0 (inclusive) to 1.0
+    /// (exclusive).
+    pub fn with_period_phase(mut self, fraction: f64) -> Self {
+        assert!(
+            (0.0..1.0).contains(&fraction),
+            "period phase must be at least 0.0 and less than 1.0, got {}",
+            fraction
+        );
+        self.period_phase = Some(fraction);
+        self
+    }
+
+    /// Choose whether a fiscal year is labeled by the year it starts or ends
+    /// in, see [`FiscalYearLabel`].
+    pub fn with_fiscal_year_label(mut self, fiscal_year_label: FiscalYearLabel) -> Self {
+        self.fiscal_year_label = fiscal_year_label;
+        self
+    }
+
+    /// Always return contiguous intervals covering the whole range.
+    ///
+    /// The first interval then starts on the boundary before `begin` and the
+    /// last one ends on the boundary after `end`, with every interval starting
+    /// `end_precision` after the end of the previous one. This overrides the
+    /// settings without extension, a partial first week, a subwindow and
+    /// clamped ends.
+    pub fn with_dense(mut self) -> Self {
+        self.dense = true;
+        self
+    }
+
+    /// Snap `begin` down and `end` up to the interval boundaries before
+    /// generating the intervals.
+    ///
+    /// The first interval then starts on the boundary at or before `begin`
+    /// and the last one ends on the boundary at or after `end`, so the
+    /// settings without extension have no effect. Unlike the default
+    /// extension of the end, no interval starting exactly at `end` is
+    /// included. A partial first week is not returned either since `begin` is
+    /// on a week boundary after snapping.
+    pub fn with_snap_inputs(mut self) -> Self {
+        self.snap_inputs = true;
+        self
+    }
+
+    /// Include the partial week containing `begin` without extended begin.
+    ///
+    /// With [`Grouping::PerWeek`] and without extended begin, the first
+    /// interval is then truncated to start at `begin` and ends with that week
+    /// instead of starting on the following Monday. This has no effect if
+    /// `begin` is exactly on a week boundary or for other groupings.
+    pub fn with_partial_first_week(mut self) -> Self {
+        self.partial_first_week = true;
+        self
+    }
+
+    /// Truncate the last interval to end at `end`.
+    ///
+    /// All other intervals stay full and the last interval keeps its
+    /// beginning, e.g. with [`Grouping::PerWeek`] and `end` on a Thursday, the
+    /// last interval runs from Monday to `end`. An interval starting exactly
+    /// at `end` is dropped. This has no effect if the last interval ends
+    /// before `end` or with [`IntervalGenerator::with_dense`].
+    pub fn with_clamped_ends(mut self) -> Self {
+        self.clamped_ends = true;
+        self
+    }
+
+    /// Include the partial last interval truncated to `end` without extended
+    /// end if it spans at least `min`.
+    ///
+    /// Without extended end, the interval containing `end` is dropped. With
+    /// this option, it is added from its boundary to `end` instead if `end`
+    /// is at least `min` after the boundary. This has no effect with extended
+    /// end, dense intervals, snapped inputs or a custom strategy.
+    pub fn with_min_last_bucket(mut self, min: Duration) -> Self {
+        self.min_last_bucket = Some(min);
+        self
+    }
+
+    /// Return the interval enclosing `begin` if `begin` and `end` are equal.
+    ///
+    /// By default, an empty list is returned for a range of zero length.
+    pub fn with_point_returns_enclosing(mut self) -> Self {
+        self.point_returns_enclosing = true;
+        self
+    }
+
+    /// Get the intervals between `begin` and `end`.
+    ///
+    /// `begin` and `end` can be in different timezones.
+    #[must_use]
+    pub fn get_intervals<T, V>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<V>,
+    ) -> Vec<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+        V: TimeZone,
+    {
+        let end = end.with_timezone(&begin.timezone());
+        self.iter_intervals(begin, end).collect()
+    }
+
+    /// Get the intervals of all `ranges` sorted by their beginning.
+    ///
+    /// The intervals are generated per range and intervals shared by
+    /// overlapping ranges are only returned once. With another anchor mode
+    /// than [`AnchorMode::Boundary`], the intervals of different ranges can
+    /// overlap without being equal and are all returned.
+    #[must_use]
+    pub fn get_intervals_for_ranges<T>(
+        &self,
+        ranges: &[(DateTime<T>, DateTime<T>)],
+    ) -> Vec<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        let mut intervals: Vec<_> = ranges
+            .iter()
+            .flat_map(|(begin, end)| self.get_intervals(begin.clone(), end.clone()))
+            .collect();
+        intervals.sort_by(cmp_by_start);
+        intervals.dedup();
+        intervals
+    }
+
+    /// Turn the generator into a closure returning the intervals between
+    /// `begin` and `end`.
+    ///
+    /// The closure is the same as calling [`IntervalGenerator::get_intervals`]
+    /// with the configuration captured once.
+    pub fn into_fn(self) -> impl Fn(DateTime<Utc>, DateTime<Utc>) -> Vec<TimeInterval<Utc>> {
+        move |begin, end| self.get_intervals(begin, end)
+    }
+
+    /// Get the first interval that [`IntervalGenerator::get_intervals`] would
+    /// return, or `None` if it would return no intervals.
+    ///
+    /// The interval is computed from the boundary at or after `begin` without
+    /// generating the following intervals. With a custom strategy, snapped
+    /// inputs, clamped ends, a minimum last bucket or a point range returning
+    /// its enclosing interval, this falls back to generating all intervals.
+    #[must_use]
+    pub fn first_interval<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Option<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        if self.strategy.is_some()
+            || self.snap_inputs
+            || self.clamped_ends
+            || self.min_last_bucket.is_some()
+            || (self.point_returns_enclosing && begin == end)
+        {
+            return self.get_intervals(begin, end).into_iter().next();
+        }
+
+        let first = match self.get_partial_first_interval(begin.clone(), end.clone()) {
+            Some(interval) => interval,
+            None => get_first_interval_impl(
+                begin,
+                end,
+                &self.grouping,
+                self.precision.end_precision(),
+                &self.local_timezone,
+                &Utc,
+                self.extend_begin || self.dense,
+                self.extend_end || self.dense,
+                &self.get_anchor_mode(),
+            )?,
+        };
+        Some(self.get_subwindow(first))
+    }
+
+    /// Get the last interval that [`IntervalGenerator::get_intervals`] would
+    /// return, or `None` if it would return no intervals.
+    ///
+    /// The interval is computed from the boundaries around `end` without
+    /// generating the preceding intervals, so it takes the same time for
+    /// ranges of any length. With a custom strategy, snapped inputs, clamped
+    /// ends, a minimum last bucket or a point range returning its enclosing
+    /// interval, this falls back to generating all intervals.
+    #[must_use]
+    pub fn last_interval<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Option<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        if self.strategy.is_some()
+            || self.snap_inputs
+            || self.clamped_ends
+            || self.min_last_bucket.is_some()
+            || (self.point_returns_enclosing && begin == end)
+        {
+            return self.get_intervals(begin, end).pop();
+        }
+
+        let partial_first_interval = self.get_partial_first_interval(begin.clone(), end.clone());
+        let last = get_last_interval_impl(
+            begin,
+            end,
+            &self.grouping,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+            self.extend_begin || self.dense,
+            self.extend_end || self.dense,
+            &self.get_anchor_mode(),
+        )
+        .or(partial_first_interval)?;
+        Some(self.get_subwindow(last))
+    }
+
+    /// Get a key for memoizing the intervals between `begin` and `end`.
+    ///
+    /// The key is a 64-bit FNV-1a hash of the grouping identifier, the
+    /// offset, the precision, the extension flags and the boundaries of the
+    /// first and last interval. Two ranges whose first and last intervals are
+    /// the same share a key, e.g. two `begin` timestamps on the same day with
+    /// [`Grouping::PerDay`]. The key does not depend on the platform or the
+    /// Rust version, so it can be persisted.
+    #[must_use]
+    pub fn cache_key<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> u64
+    where
+        T: TimeZone,
+    {
+        let mut hasher = Fnv1a::new();
+        hasher.write(self.grouping.id().as_bytes());
+        hasher.write(&[0xff]);
+        hasher.write(&self.local_timezone.local_minus_utc().to_le_bytes());
+        hasher.write_option_i64(self.precision.end_precision().num_nanoseconds());
+        hasher.write(&[self.extend_begin as u8, self.extend_end as u8]);
+        for interval in [
+            self.first_interval(begin.clone(), end.clone()),
+            self.last_interval(begin, end),
+        ] {
+            match interval {
+                Some((interval_begin, interval_end)) => {
+                    hasher.write(&[1]);
+                    for datetime in [interval_begin, interval_end] {
+                        hasher.write(&datetime.timestamp().to_le_bytes());
+                        hasher.write(&datetime.timestamp_subsec_nanos().to_le_bytes());
+                    }
+                }
+                None => hasher.write(&[0]),
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Get the intervals between `begin` and `end` sorted by their duration.
+    ///
+    /// The sort is stable, so intervals of equal duration stay in
+    /// chronological order. With `descending`, the longest intervals come
+    /// first, e.g. the months with 31 days before those with 30 days and
+    /// February last for [`Grouping::PerMonth`].
+    #[must_use]
+    pub fn get_intervals_sorted_by_duration<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        descending: bool,
+    ) -> Vec<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        let mut intervals = self.get_intervals(begin, end);
+        match descending {
+            true => intervals.sort_by_key(|interval| Reverse(interval.1 - interval.0)),
+            false => intervals.sort_by_key(|interval| interval.1 - interval.0),
+        }
+        intervals
+    }
+
+    /// Get the intervals between `begin` and `end` for each of `groupings`.
+    ///
+    /// All other settings are shared between the groupings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a subwindow is set which does not fit into the intervals of
+    /// one of `groupings`.
+    #[must_use]
+    pub fn get_intervals_multi<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        groupings: &[Grouping],
+    ) -> HashMap<Grouping, Vec<TimeInterval<Utc>>>
+    where
+        T: TimeZone,
+    {
+        groupings
+            .iter()
+            .map(|grouping| {
+                let intervals = self
+                    .clone()
+                    .with_grouping(*grouping)
+                    .get_intervals(begin.clone(), end.clone());
+                (*grouping, intervals)
+            })
+            .collect()
+    }
+
+    /// Get the daily intervals between `begin` and `end` grouped by their
+    /// weekday.
+    ///
+    /// The intervals are generated with [`Grouping::PerDay`] regardless of the
+    /// grouping and keyed by their weekday in the local timezone.
+    #[must_use]
+    pub fn group_by_weekday<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> HashMap<Weekday, Vec<TimeInterval<Utc>>>
+    where
+        T: TimeZone,
+    {
+        let mut intervals_per_weekday: HashMap<Weekday, Vec<TimeInterval<Utc>>> = HashMap::new();
+        for interval in self
+            .clone()
+            .with_grouping(Grouping::PerDay)
+            .get_intervals(begin, end)
+        {
+            let weekday = interval.0.with_timezone(&self.local_timezone).weekday();
+            intervals_per_weekday
+                .entry(weekday)
+                .or_default()
+                .push(interval);
+        }
+        intervals_per_weekday
+    }
+
+    /// Get the index of the weekday of `timestamp` in the local timezone.
+    ///
+    /// The index is from 0 to 6 with the numbering set by
+    /// [`IntervalGenerator::with_weekday_numbering`].
+    #[must_use]
+    pub fn weekday_index<T>(&self, timestamp: DateTime<T>) -> u32
+    where
+        T: TimeZone,
+    {
+        self.weekday_numbering
+            .index_of(timestamp.with_timezone(&self.local_timezone).weekday())
+    }
+
+    /// Get the runs of consecutive open days between `begin` and `end`.
+    ///
+    /// The days are generated with [`Grouping::PerDay`] regardless of the
+    /// grouping. Days whose local date is in `closed` are left out and each
+    /// run of consecutive remaining days is merged into a single interval
+    /// from the beginning of its first day to the end of its last day.
+    #[must_use]
+    pub fn get_open_runs<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        closed: &BTreeSet<NaiveDate>,
+    ) -> Vec<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        let mut runs: Vec<TimeInterval<Utc>> = Vec::new();
+        let mut previous_open = false;
+        for interval in self
+            .clone()
+            .with_grouping(Grouping::PerDay)
+            .get_intervals(begin, end)
+        {
+            let date = interval
+                .0
+                .with_timezone(&self.local_timezone)
+                .naive_local()
+                .date();
+            let open = !closed.contains(&date);
+            match (open, previous_open, runs.last_mut()) {
+                (true, true, Some(run)) => run.1 = interval.1,
+                (true, _, _) => runs.push(interval),
+                (false, _, _) => {}
+            }
+            previous_open = open;
+        }
+        runs
+    }
+
+    /// Get the weekly intervals of the ISO week-year `year` keyed by their
+    /// week number.
+    ///
+    /// An ISO week-year has 52 or 53 weeks, e.g. 53 weeks in 2020, and its
+    /// first week is the one containing January 4th. The weeks start on
+    /// Monday in the local timezone regardless of the grouping and the week
+    /// start. The list is empty if the weeks of `year` are out of the range
+    /// supported by `chrono`.
+    #[must_use]
+    pub fn intervals_by_iso_week(&self, year: i32) -> Vec<(u32, TimeInterval<Utc>)> {
+        let Some((begin, num_weeks)) = self.get_iso_week_year(year) else {
+            return Vec::with_capacity(0);
+        };
+
+        get_n_intervals_impl(
+            begin,
+            num_weeks,
+            &Grouping::PerWeek,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+            true,
+            &AnchorMode::Boundary,
+        )
+        .into_iter()
+        .zip(1..)
+        .map(|(interval, week)| (week, interval))
+        .collect()
+    }
+
+    /// Get the intervals between `begin` and `end` numbered by the week since
+    /// `kickoff` they begin in.
+    ///
+    /// The week number is `floor((begin of interval - kickoff) / 7 days) + 1`,
+    /// so the intervals beginning in the first seven days from `kickoff` on
+    /// are in week 1, e.g. for project timelines. An interval beginning less
+    /// than seven days before `kickoff` is in week 0, intervals beginning
+    /// earlier are skipped.
+    #[must_use]
+    pub fn week_numbers_from<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        kickoff: DateTime<Utc>,
+    ) -> Vec<(u32, TimeInterval<Utc>)>
+    where
+        T: TimeZone,
+    {
+        let week_secs = Duration::weeks(1).num_seconds();
+        self.get_intervals(begin, end)
+            .into_iter()
+            .filter_map(|interval| {
+                let num_weeks = (interval.0 - kickoff).num_seconds().div_euclid(week_secs);
+                Some((u32::try_from(num_weeks + 1).ok()?, interval))
+            })
+            .collect()
+    }
+
+    /// Get the intervals between `begin` and `end`, failing on invalid input.
+    ///
+    /// Unlike [`IntervalGenerator::get_intervals`], which returns no intervals
+    /// for a reversed range, this returns [`IntervalError::ReversedRange`] if
+    /// `begin` is after `end`. An empty range with `begin` equal to `end` is
+    /// not an error. If the end precision is not shorter than the shortest
+    /// interval of the grouping, [`IntervalError::PrecisionTooLarge`] is
+    /// returned. If both an offset and wall-clock days are set,
+    /// [`IntervalError::ConflictingTimezones`] is returned. An offset of a day
+    /// or more results in [`IntervalError::InvalidOffset`].
+    pub fn get_intervals_checked<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Result<Vec<TimeInterval<Utc>>, IntervalError>
+    where
+        T: TimeZone,
+    {
+        self.check_precision()?;
+        self.check_timezones()?;
+        if let Some(offset_west_secs) = self.invalid_offset {
+            return Err(IntervalError::InvalidOffset(offset_west_secs));
+        }
+        if begin > end {
+            return Err(IntervalError::ReversedRange);
+        }
+        Ok(self.get_intervals(begin, end))
+    }
+
+    /// Get the intervals between `begin` and `end` together with their labels.
+    ///
+    /// See [`crate::get_interval_label`] for the label format.
+    #[must_use]
+    pub fn get_labeled_intervals<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Vec<LabeledInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .map(|interval| LabeledInterval {
+                label: self.get_label(&interval),
+                interval,
+                timezone_name: self.timezone_name.clone(),
+            })
+            .collect()
+    }
+
+    /// Get the intervals between `begin` and `end` with labels counting them
+    /// within the range.
+    ///
+    /// The labels are the name of the grouping followed by the position of
+    /// the interval starting at 1, e.g. `Day 1`, `Day 2` and so on for
+    /// [`Grouping::PerDay`] or `Week 1` for [`Grouping::PerWeek`].
+    #[must_use]
+    pub fn get_ordinal_labeled_intervals<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Vec<(String, TimeInterval<Utc>)>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .zip(1..)
+            .map(|(interval, n)| (format!("{} {}", self.grouping.ordinal_name(), n), interval))
+            .collect()
+    }
+
+    /// Get the interval between `begin` and `end` containing the instant
+    /// `offset` after `begin`.
+    ///
+    /// Returns `None` if the instant is before `begin`, after `end` or in the
+    /// gap of `end_precision` between two intervals. Without extension, the
+    /// instant can also be outside of all intervals.
+    #[must_use]
+    pub fn interval_at_offset<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        offset: Duration,
+    ) -> Option<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        let timestamp = begin.clone().checked_add_signed(offset)?;
+        if timestamp < begin || timestamp > end {
+            return None;
+        }
+        let intervals = self.get_intervals(begin, end);
+        let index = find_interval_containing(&intervals, &timestamp.with_timezone(&Utc))?;
+        Some(intervals[index])
+    }
+
+    /// Get intervals between `begin` and `end` together with their
+    /// predecessor and successor within the same range.
+    ///
+    /// The first interval has no predecessor and the last interval has no
+    /// successor.
+    #[must_use]
+    pub fn get_intervals_with_neighbors<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Vec<NeighboredInterval>
+    where
+        T: TimeZone,
+    {
+        let intervals = self.get_intervals(begin, end);
+        (0..intervals.len())
+            .map(|i| {
+                (
+                    i.checked_sub(1).map(|prev| intervals[prev]),
+                    intervals[i],
+                    intervals.get(i + 1).copied(),
+                )
+            })
+            .collect()
+    }
+
+    /// Get the full interval before the one enclosing `begin`.
+    ///
+    /// The interval is aligned to the boundaries of the grouping regardless
+    /// of the anchor mode. A custom strategy is not supported since it can
+    /// only step forward, so the grouping is used even if a strategy is set.
+    pub fn previous_interval<T>(&self, begin: DateTime<T>) -> TimeInterval<Utc>
+    where
+        T: TimeZone,
+    {
+        if self.get_boundary_anchor().is_some() {
+            let (enclosing_begin, _) = self.get_shifted_intervals(begin, 1)[0];
+            return self.get_shifted_intervals(enclosing_begin - Duration::nanoseconds(1), 1)[0];
+        }
+        get_previous_interval_impl(
+            begin,
+            &self.grouping,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+        )
+    }
+
+    /// Get the most recent interval which has fully elapsed at `now`.
+    ///
+    /// This is e.g. yesterday for [`Grouping::PerDay`] or the last month for
+    /// [`Grouping::PerMonth`]. If `now` is exactly on a boundary, the interval
+    /// ending right before it is returned.
+    pub fn latest_complete_interval(&self, now: DateTime<Utc>) -> TimeInterval<Utc> {
+        self.previous_interval(now)
+    }
+
+    /// Get the time from `now` until the next interval boundary.
+    ///
+    /// This is e.g. the time until the next midnight in the local timezone
+    /// for [`Grouping::PerDay`]. If `now` is exactly on a boundary, the time
+    /// until the boundary after it is returned.
+    pub fn duration_until_next_boundary(&self, now: DateTime<Utc>) -> Duration {
+        self.next_interval(now).0 - now
+    }
+
+    /// Get the `n` most recent intervals which have fully elapsed at `now` in
+    /// chronological order.
+    ///
+    /// This is e.g. the last 12 months for [`Grouping::PerMonth`] and an `n`
+    /// of 12. The last interval is the
+    /// [`IntervalGenerator::latest_complete_interval`]. The intervals follow
+    /// the grouping even if a custom strategy is set, see
+    /// [`IntervalGenerator::previous_interval`].
+    pub fn trailing_periods(&self, now: DateTime<Utc>, n: usize) -> Vec<TimeInterval<Utc>> {
+        let mut intervals = Vec::with_capacity(n);
+        let mut cur_begin = now;
+        for _ in 0..n {
+            let interval = self.previous_interval(cur_begin);
+            cur_begin = interval.0;
+            intervals.push(interval);
+        }
+        intervals.reverse();
+        intervals
+    }
+
+    /// Get the full interval after the one enclosing `end`.
+    ///
+    /// The interval is aligned to the boundaries of the grouping regardless
+    /// of the anchor mode. Like with [`IntervalGenerator::previous_interval`],
+    /// the grouping is used even if a custom strategy is set.
+    pub fn next_interval<T>(&self, end: DateTime<T>) -> TimeInterval<Utc>
+    where
+        T: TimeZone,
+    {
+        if self.get_boundary_anchor().is_some() {
+            return self.get_shifted_intervals(end, 2)[1];
+        }
+        get_next_interval_impl(
+            end,
+            &self.grouping,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+        )
+    }
+
+    /// Get the intervals both in the local timezone and in UTC.
+    ///
+    /// Each entry holds the interval boundaries in the local timezone set by
+    /// the offset as well as the same interval converted to UTC. This helps
+    /// to inspect how the offset was applied.
+    #[must_use]
+    pub fn get_intervals_with_local<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Vec<(TimeInterval<FixedOffset>, TimeInterval<Utc>)>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .map(|interval| {
+                (
+                    (
+                        interval.0.with_timezone(&self.local_timezone),
+                        interval.1.with_timezone(&self.local_timezone),
+                    ),
+                    interval,
+                )
+            })
+            .collect()
+    }
+
+    /// Get intervals between `begin` and `end` with named fields instead of
+    /// tuples.
+    #[must_use]
+    pub fn get_intervals_structured<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Vec<Interval<Utc>>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .map(Interval::from)
+            .collect()
+    }
+
+    /// Get the number of whole days in each interval between `begin` and
+    /// `end`.
+    ///
+    /// This is e.g. 28 to 31 for [`Grouping::PerMonth`], 7 for
+    /// [`Grouping::PerWeek`] and 0 for [`Grouping::PerQuarterHour`]. The end
+    /// precision is added back to the interval length before counting.
+    #[must_use]
+    pub fn days_in_each_interval<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> Vec<u32>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .map(|interval| {
+                let num_days =
+                    (interval.1 - interval.0 + self.precision.end_precision()).num_days();
+                u32::try_from(num_days).unwrap_or(0)
+            })
+            .collect()
+    }
+
+    /// Get the intervals together with the local date they start on.
+    ///
+    /// The date is the date of the interval beginning in the local timezone
+    /// set by the offset, which can differ from the date in UTC.
+    #[must_use]
+    pub fn get_intervals_with_local_date<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Vec<(NaiveDate, TimeInterval<Utc>)>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .map(|interval| {
+                (
+                    interval
+                        .0
+                        .with_timezone(&self.local_timezone)
+                        .naive_local()
+                        .date(),
+                    interval,
+                )
+            })
+            .collect()
+    }
+
+    /// Get the beginnings and ends of the intervals as two parallel columns
+    /// of Unix timestamps in milliseconds.
+    ///
+    /// The columns map directly onto timestamp columns of e.g. Apache Arrow or
+    /// Polars. Anything below millisecond precision is truncated, see
+    /// [`crate::interval_to_millis`].
+    #[cfg(feature = "columnar")]
+    #[must_use]
+    pub fn get_intervals_columnar<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> (Vec<i64>, Vec<i64>)
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .iter()
+            .map(crate::interval_to_millis)
+            .unzip()
+    }
+
+    /// Get the intervals together with their durations.
+    ///
+    /// The duration of each interval is its end minus its beginning, so it is
+    /// `end_precision` shorter than the time between two boundaries and varies
+    /// e.g. with the length of months.
+    #[must_use]
+    pub fn get_intervals_with_duration<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Vec<(TimeInterval<Utc>, Duration)>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .map(|interval| (interval, interval.1 - interval.0))
+            .collect()
+    }
+
+    /// Get the intervals between `begin` and `end` as [`Intervals`].
+    #[must_use]
+    pub fn get_intervals_wrapped<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> Intervals<Utc>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end).into()
+    }
+
+    /// Get the boundaries of the intervals between `begin` and `end`.
+    ///
+    /// These are the beginnings of all intervals followed by the boundary
+    /// after the last interval, i.e. `n + 1` boundaries for `n` intervals. If
+    /// the last interval is saturated at the maximum date time, its end is the
+    /// last boundary.
+    #[must_use]
+    pub fn get_boundaries<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> Vec<DateTime<Utc>>
+    where
+        T: TimeZone,
+    {
+        let intervals = self.get_intervals(begin, end);
+        let last_boundary = intervals.last().map(|last| {
+            last.1
+                .checked_add_signed(self.precision.end_precision())
+                .unwrap_or(last.1)
+        });
+        intervals
+            .iter()
+            .map(|interval| interval.0)
+            .chain(last_boundary)
+            .collect()
+    }
+
+    /// Get the beginnings and ends of the intervals between `begin` and `end`
+    /// as sorted set.
+    ///
+    /// The set allows fast membership and `range` queries. Unlike
+    /// [`IntervalGenerator::get_boundaries`], it holds the ends of the
+    /// intervals instead of the boundaries after them, so it has `2 * n`
+    /// entries for `n` intervals unless beginnings and ends coincide, e.g.
+    /// without end precision.
+    #[must_use]
+    pub fn boundary_set<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> BTreeSet<DateTime<Utc>>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .flat_map(|interval| [interval.0, interval.1])
+            .collect()
+    }
+
+    /// Get the beginnings of the intervals between `begin` and `end` as Unix
+    /// timestamps in seconds.
+    ///
+    /// These are the bucket timestamps that time-series databases such as
+    /// InfluxDB or Prometheus key on. Anything below a second is truncated.
+    #[must_use]
+    pub fn bucket_timestamps<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> Vec<i64>
+    where
+        T: TimeZone,
+    {
+        self.get_intervals(begin, end)
+            .into_iter()
+            .map(|interval| interval.0.timestamp())
+            .collect()
+    }
+
+    /// Get the span from `begin` to `end` covered by the intervals.
+    ///
+    /// The span reaches from the beginning of the first interval to the end of
+    /// the last one. Without extension, it can be shorter than the range from
+    /// `begin` to `end`. If there are no intervals, `(begin, begin)` is
+    /// returned.
+    pub fn coverage<T>(&self, begin: DateTime<T>, end: DateTime<T>) -> TimeInterval<Utc>
+    where
+        T: TimeZone,
+    {
+        let intervals = self.get_intervals(begin.clone(), end);
+        match (intervals.first(), intervals.last()) {
+            (Some(first), Some(last)) => (first.0, last.1),
+            _ => (begin.with_timezone(&Utc), begin.with_timezone(&Utc)),
+        }
+    }
+
+    /// Get up to `limit` intervals between `begin` and `end`, skipping the
+    /// first `offset` ones.
+    ///
+    /// The result equals the slice `[offset, offset + limit)` of
+    /// [`IntervalGenerator::get_intervals`] but the skipped intervals are not
+    /// allocated.
+    #[must_use]
+    pub fn get_intervals_paged<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        self.iter_intervals(begin, end)
+            .skip(offset)
+            .take(limit)
+            .collect()
+    }
+
+    /// Get the intervals between `begin` and `end` in chunks of at most
+    /// `chunk_size` intervals.
+    ///
+    /// The chunks are computed lazily from a single pass over the intervals
+    /// when iterating, e.g. to yield to an async runtime in between.
+    /// Flattened, they equal [`IntervalGenerator::get_intervals`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn get_intervals_chunked<'a, T>(
+        &'a self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = Vec<TimeInterval<Utc>>> + 'a
+    where
+        T: TimeZone + 'a,
+    {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        let mut intervals = self.iter_intervals(begin, end);
+        std::iter::from_fn(move || {
+            let chunk = intervals.by_ref().take(chunk_size).collect::<Vec<_>>();
+            match chunk.is_empty() {
+                true => None,
+                false => Some(chunk),
+            }
+        })
+    }
+
+    /// Send the intervals between `begin` and `end` to `tx` one by one.
+    ///
+    /// The intervals are generated lazily in a single pass, so a consumer on
+    /// another thread can process them while the rest are generated. They
+    /// equal [`IntervalGenerator::get_intervals`] and generation stops as soon
+    /// as the receiver hangs up.
+    pub fn generate_into_sender<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        tx: Sender<TimeInterval<Utc>>,
+    ) where
+        T: TimeZone,
+    {
+        for interval in self.iter_intervals(begin, end) {
+            if tx.send(interval).is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Get exactly `count` consecutive intervals starting from `begin`.
+    ///
+    /// The first interval starts on the boundary before `begin` (or after it
+    /// without extended begin). The end setting does not apply.
+    #[must_use]
+    pub fn get_n_intervals<T>(&self, begin: DateTime<T>, count: usize) -> Vec<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        if let Some(strategy) = &self.strategy {
+            return self.apply_subwindow(get_n_strategy_intervals_impl(
+                begin,
+                count,
+                strategy.as_ref(),
+                self.precision.end_precision(),
+                &self.local_timezone,
+                &Utc,
+                self.extend_begin,
+            ));
+        }
+
+        self.apply_subwindow(get_n_intervals_impl(
+            begin,
+            count,
+            &self.grouping,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+            self.extend_begin,
+            &self.get_anchor_mode(),
+        ))
+    }
+}
+
+impl<P: Precision> IntervalGenerator<P> {
+    /// Iterate lazily over the intervals that
+    /// [`IntervalGenerator::get_intervals`] returns.
+    fn iter_intervals<'a, T>(
+        &'a self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> impl Iterator<Item = TimeInterval<Utc>> + 'a
+    where
+        T: TimeZone + 'a,
+    {
+        let min_last_bucket = self.get_min_last_bucket(begin.clone(), end.clone());
+        let utc_end = end.with_timezone(&Utc);
+        self.iter_untrimmed_intervals(begin, end)
+            .map(|interval| self.get_subwindow(interval))
+            .chain(min_last_bucket)
+            .filter_map(move |interval| self.clamp_end(interval, utc_end))
+    }
+
+    fn iter_untrimmed_intervals<'a, T>(
+        &'a self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Box<dyn Iterator<Item = TimeInterval<Utc>> + 'a>
     where
         T: TimeZone,
     {
-        get_intervals_impl(
+        if self.point_returns_enclosing && begin == end {
+            let intervals = match &self.strategy {
+                Some(strategy) => get_n_strategy_intervals_impl(
+                    begin,
+                    1,
+                    strategy.as_ref(),
+                    self.precision.end_precision(),
+                    &self.local_timezone,
+                    &Utc,
+                    true,
+                ),
+                None => get_n_intervals_impl(
+                    begin,
+                    1,
+                    &self.grouping,
+                    self.precision.end_precision(),
+                    &self.local_timezone,
+                    &Utc,
+                    true,
+                    &self.get_anchor_mode(),
+                ),
+            };
+            return Box::new(intervals.into_iter());
+        }
+
+        let extend_begin = self.extend_begin || self.dense || self.snap_inputs;
+        let extend_end = self.extend_end || self.dense || self.snap_inputs;
+        let utc_end = end.with_timezone(&Utc);
+        if let Some(strategy) = &self.strategy {
+            return Box::new(
+                IntervalIter::with_strategy(
+                    begin,
+                    end,
+                    strategy.as_ref(),
+                    self.precision.end_precision(),
+                    &self.local_timezone,
+                    &Utc,
+                    extend_begin,
+                    extend_end,
+                )
+                .filter_map(move |interval| self.snap_end(interval, utc_end)),
+            );
+        }
+
+        let partial_first_interval = self.get_partial_first_interval(begin.clone(), end.clone());
+        let intervals = IntervalIter::new(
             begin,
             end,
             &self.grouping,
-            self.end_precision,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+            extend_begin,
+            extend_end,
+            &self.get_anchor_mode(),
+        )
+        .filter_map(move |interval| self.snap_end(interval, utc_end));
+        #[cfg(any(test, feature = "test-util"))]
+        let intervals = intervals.inspect(|interval| self.assert_week_start(interval));
+        Box::new(partial_first_interval.into_iter().chain(intervals))
+    }
+
+    /// Drop the interval starting exactly at `end` when snapping the inputs.
+    fn snap_end(
+        &self,
+        interval: TimeInterval<Utc>,
+        end: DateTime<Utc>,
+    ) -> Option<TimeInterval<Utc>> {
+        match self.snap_inputs && interval.0 == end {
+            true => None,
+            false => Some(interval),
+        }
+    }
+
+    /// Get the partial last interval truncated to `end` if it spans at least
+    /// the minimum last bucket.
+    fn get_min_last_bucket<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Option<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        let min = self.min_last_bucket?;
+        if self.extend_end || self.dense || self.snap_inputs || self.strategy.is_some() {
+            return None;
+        }
+
+        let (bucket_begin, _) = get_last_interval_impl(
+            begin,
+            end.clone(),
+            &self.grouping,
+            self.precision.end_precision(),
             &self.local_timezone,
             &Utc,
             self.extend_begin,
-            self.extend_end,
+            true,
+            &self.get_anchor_mode(),
+        )?;
+        let end = end.with_timezone(&Utc);
+        match bucket_begin < end && end - bucket_begin >= min {
+            true => Some((bucket_begin, end)),
+            false => None,
+        }
+    }
+
+    /// Truncate the last interval to `end` with clamped ends unless the
+    /// intervals are dense.
+    ///
+    /// Only the last interval can start at or end after `end`, so each
+    /// interval is clamped on its own.
+    fn clamp_end(
+        &self,
+        interval: TimeInterval<Utc>,
+        end: DateTime<Utc>,
+    ) -> Option<TimeInterval<Utc>> {
+        if !self.clamped_ends || self.dense {
+            return Some(interval);
+        }
+        match interval.0 == end {
+            true => None,
+            false => Some((interval.0, interval.1.min(end))),
+        }
+    }
+
+    /// Check that a week interval starts on the week start in the local
+    /// timezone.
+    #[cfg(any(test, feature = "test-util"))]
+    fn assert_week_start(&self, interval: &TimeInterval<Utc>) {
+        if let (Grouping::PerWeek, AnchorMode::Boundary, None) =
+            (&self.grouping, &self.anchor_mode, self.period_phase)
+        {
+            let week_start = self.week_start.unwrap_or(Weekday::Mon);
+            crate::test_util::assert_weeks_start_on(
+                std::slice::from_ref(interval),
+                week_start,
+                -self.local_timezone.local_minus_utc(),
+            );
+        }
+    }
+
+    fn apply_subwindow(&self, intervals: Vec<TimeInterval<Utc>>) -> Vec<TimeInterval<Utc>> {
+        match self.subwindow.filter(|_| !self.dense) {
+            Some(_) => intervals
+                .into_iter()
+                .map(|interval| self.get_subwindow(interval))
+                .collect(),
+            None => intervals,
+        }
+    }
+
+    /// Get the subwindow of a single interval if a subwindow is set.
+    fn get_subwindow(&self, interval: TimeInterval<Utc>) -> TimeInterval<Utc> {
+        match self.subwindow.filter(|_| !self.dense) {
+            Some((start_offset, length)) => {
+                let subwindow_begin = interval.0 + start_offset;
+                (
+                    subwindow_begin,
+                    subwindow_begin + length - self.precision.end_precision(),
+                )
+            }
+            None => interval,
+        }
+    }
+
+    /// Get the anchor mode with the fiscal year or day start applied.
+    fn get_anchor_mode(&self) -> AnchorMode {
+        match self.get_boundary_anchor() {
+            Some(anchor) => AnchorMode::At(anchor),
+            None => self.anchor_mode,
+        }
+    }
+
+    /// Get the anchor of intervals whose boundaries are shifted by the fiscal
+    /// year, day start, week start or period phase.
+    fn get_boundary_anchor(&self) -> Option<DateTime<Utc>> {
+        if !matches!(self.anchor_mode, AnchorMode::Boundary) {
+            return None;
+        }
+        let shifted_start = match &self.grouping {
+            Grouping::PerYear | Grouping::PerRetail445 => {
+                self.fiscal_year_start.and_then(|(month, day)| {
+                    NaiveDate::from_ymd_opt(2001, month, day)?.and_hms_opt(0, 0, 0)
+                })
+            }
+            Grouping::PerDay => self
+                .day_start_time
+                .and_then(|time| Some(NaiveDate::from_ymd_opt(2001, 1, 1)?.and_time(time))),
+            // 2001-01-01 is a Monday
+            Grouping::PerWeek => self.week_start.and_then(|weekday| {
+                (NaiveDate::from_ymd_opt(2001, 1, 1)?
+                    + Duration::days(weekday.num_days_from_monday() as i64))
+                .and_hms_opt(0, 0, 0)
+            }),
+            _ => None,
+        };
+        if shifted_start.is_none() && self.period_phase.is_none() {
+            return None;
+        }
+
+        // 2001-01-01 is on the boundary of all groupings
+        let start = match shifted_start {
+            Some(shifted_start) => shifted_start,
+            None => NaiveDate::from_ymd_opt(2001, 1, 1)?.and_hms_opt(0, 0, 0)?,
+        };
+        let start = self.local_timezone.from_local_datetime(&start).single()?;
+        let anchor = match self.period_phase {
+            Some(fraction) => {
+                let period_length = get_rules(&self.grouping).nth_anchored_begin(start, 1)? - start;
+                start
+                    + Duration::nanoseconds(
+                        (period_length.num_nanoseconds()? as f64 * fraction) as i64,
+                    )
+            }
+            None => start,
+        };
+        Some(anchor.with_timezone(&Utc))
+    }
+
+    /// Get `count` intervals with shifted boundaries starting with the one
+    /// enclosing `begin`.
+    fn get_shifted_intervals<T>(&self, begin: DateTime<T>, count: usize) -> Vec<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        get_n_intervals_impl(
+            begin,
+            count,
+            &self.grouping,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+            true,
+            &self.get_anchor_mode(),
+        )
+    }
+
+    fn get_label(&self, interval: &TimeInterval<Utc>) -> String {
+        if self.label_at_end {
+            let label_grouping = match self.grouping {
+                Grouping::PerQuarterHour => Grouping::PerQuarterHour,
+                _ => Grouping::PerDay,
+            };
+            return format_label(&interval.1, &label_grouping, &self.local_timezone);
+        }
+        if !matches!(self.grouping, Grouping::PerYear)
+            || !matches!(self.anchor_mode, AnchorMode::Boundary)
+            || self.fiscal_year_start.is_none()
+        {
+            return format_label(&interval.0, &self.grouping, &self.local_timezone);
+        }
+
+        // A fiscal year starting on January 1st ends in the same year
+        let start_year = interval.0.with_timezone(&self.local_timezone).year();
+        match (&self.fiscal_year_label, self.fiscal_year_start) {
+            (FiscalYearLabel::EndYear, Some((month, day))) if (month, day) != (1, 1) => {
+                format!("FY{}", start_year + 1)
+            }
+            _ => format!("FY{}", start_year),
+        }
+    }
+
+    fn check_precision(&self) -> Result<(), IntervalError> {
+        match self.precision.end_precision() < self.grouping.shortest_length() {
+            true => Ok(()),
+            false => Err(IntervalError::PrecisionTooLarge),
+        }
+    }
+
+    fn check_timezones(&self) -> Result<(), IntervalError> {
+        match self.offset_set && self.wall_clock_days {
+            true => Err(IntervalError::ConflictingTimezones),
+            false => Ok(()),
+        }
+    }
+
+    fn assert_subwindow_fits(&self) {
+        if let Some((start_offset, length)) = self.subwindow {
+            assert!(
+                start_offset + length <= self.grouping.shortest_length(),
+                "subwindow does not fit into the intervals of grouping {:?}",
+                self.grouping.id()
+            );
+        }
+    }
+
+    /// Get the beginning of the ISO week-year `year` in the local timezone and
+    /// its number of weeks.
+    fn get_iso_week_year(&self, year: i32) -> Option<(DateTime<FixedOffset>, usize)> {
+        let first_monday = NaiveDate::from_isoywd_opt(year, 1, Weekday::Mon)?;
+        let next_first_monday = NaiveDate::from_isoywd_opt(year.checked_add(1)?, 1, Weekday::Mon)?;
+        let begin = self
+            .local_timezone
+            .from_local_datetime(&first_monday.and_hms_opt(0, 0, 0)?)
+            .single()?;
+        Some((
+            begin,
+            (next_first_monday - first_monday).num_weeks() as usize,
+        ))
+    }
+
+    fn get_partial_first_interval<T>(
+        &self,
+        begin: DateTime<T>,
+        end: DateTime<T>,
+    ) -> Option<TimeInterval<Utc>>
+    where
+        T: TimeZone,
+    {
+        if !self.partial_first_week
+            || self.extend_begin
+            || self.dense
+            || self.snap_inputs
+            || !matches!(self.anchor_mode, AnchorMode::Boundary)
+            || !matches!(self.grouping, Grouping::PerWeek)
+            || begin >= end
+        {
+            return None;
+        }
+
+        let begin = begin.with_timezone(&Utc);
+        let end = end.with_timezone(&Utc);
+        let (week_begin, week_end) = get_n_intervals_impl(
+            begin,
+            1,
+            &self.grouping,
+            self.precision.end_precision(),
+            &self.local_timezone,
+            &Utc,
+            true,
+            &self.get_anchor_mode(),
         )
+        .pop()?;
+
+        if week_begin == begin || (week_end >= end && !self.extend_end) {
+            return None;
+        }
+        Some((begin, week_end))
     }
 }
 
@@ -76,3 +1786,35 @@ impl Default for IntervalGenerator {
         IntervalGenerator::new()
     }
 }
+
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Fnv1a(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_option_i64(&mut self, value: Option<i64>) {
+        match value {
+            Some(value) => {
+                self.write(&[1]);
+                self.write(&value.to_le_bytes());
+            }
+            None => self.write(&[0]),
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -3,9 +3,141 @@
 //! Specify in which chunks time intervals should be grouped. Time intervals
 //! with e.g. `Grouping::PerDay` have a length of 24h minus the duration of
 //! `end_precision` (default 1ms).
+//! Intervals per quarter-hour start at :00, :15, :30 and :45 in the local
+//! timezone.
 //! Intervals per week start on Monday and end on Sunday night.
+//! Intervals per two weeks start on the Monday of the first week, unless they
+//! are anchored to another date with `AnchorMode::At`.
+//! Intervals per year start on January 1st, unless a fiscal year start is set
+//! with `IntervalGenerator::with_fiscal_year_start`.
+//! Intervals per 4-4-5 retail period split every year into four quarters of
+//! 13 weeks with periods of four, four and five weeks. The year starts on
+//! January 1st or the fiscal year start and its last period is extended to
+//! the start of the next year.
+use std::str::FromStr;
+
+use chrono::Duration;
+
+use crate::IntervalError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Grouping {
+    PerQuarterHour,
+    /// Days from midnight to midnight in the local timezone.
+    ///
+    /// With an offset east of UTC, a local day starts on the previous day in
+    /// UTC, so the intervals straddle month ends in UTC:
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, TimeZone, Utc};
+    /// use chrono_intervals::{Grouping, IntervalGenerator};
+    ///
+    /// let begin = DateTime::parse_from_rfc3339("2022-06-30T05:00:00.000000+07:00").unwrap();
+    /// let end = DateTime::parse_from_rfc3339("2022-07-01T10:00:00.000000+07:00").unwrap();
+    ///
+    /// let daily_intervals = IntervalGenerator::new()
+    ///     .with_grouping(Grouping::PerDay)
+    ///     .with_offset_west_secs(-7 * 3600)
+    ///     .get_intervals(begin, end);
+    ///
+    /// assert_eq!(
+    ///     daily_intervals,
+    ///     vec![
+    ///         (
+    ///             Utc.ymd(2022, 6, 29).and_hms(17, 0, 0),
+    ///             Utc.ymd(2022, 6, 30).and_hms_milli(16, 59, 59, 999),
+    ///         ),
+    ///         (
+    ///             Utc.ymd(2022, 6, 30).and_hms(17, 0, 0),
+    ///             Utc.ymd(2022, 7, 1).and_hms_milli(16, 59, 59, 999),
+    ///         ),
+    ///     ]
+    /// );
+    /// ```
     PerDay,
     PerWeek,
+    PerTwoWeeks,
     PerMonth,
+    PerYear,
+    PerRetail445,
+}
+
+impl Grouping {
+    /// Get a stable machine identifier of the grouping.
+    ///
+    /// The returned strings are guaranteed to stay the same across versions
+    /// and can be used e.g. as keys in a database.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Grouping::PerQuarterHour => "quarter_hour",
+            Grouping::PerDay => "day",
+            Grouping::PerWeek => "week",
+            Grouping::PerTwoWeeks => "two_weeks",
+            Grouping::PerMonth => "month",
+            Grouping::PerYear => "year",
+            Grouping::PerRetail445 => "retail_445",
+        }
+    }
+
+    /// Get the `chrono` format string of the interval labels.
+    ///
+    /// Formatting the beginning of an interval in the local timezone with this
+    /// string yields the label of the interval, see
+    /// [`crate::get_interval_label`].
+    pub fn label_format(&self) -> &'static str {
+        match self {
+            Grouping::PerQuarterHour => "%Y-%m-%dT%H:%M",
+            Grouping::PerDay => "%Y-%m-%d",
+            Grouping::PerWeek => "%G-W%V",
+            Grouping::PerTwoWeeks => "%G-W%V",
+            Grouping::PerMonth => "%Y-%m",
+            Grouping::PerYear => "%Y",
+            Grouping::PerRetail445 => "%Y-%m-%d",
+        }
+    }
+
+    /// Get the name of a single interval in ordinal labels, e.g. `Day` for
+    /// `Day 1`.
+    pub(crate) fn ordinal_name(&self) -> &'static str {
+        match self {
+            Grouping::PerQuarterHour => "Quarter hour",
+            Grouping::PerDay => "Day",
+            Grouping::PerWeek => "Week",
+            Grouping::PerTwoWeeks => "Two weeks",
+            Grouping::PerMonth => "Month",
+            Grouping::PerYear => "Year",
+            Grouping::PerRetail445 => "Period",
+        }
+    }
+
+    /// Get the length of the shortest interval of the grouping.
+    pub(crate) fn shortest_length(&self) -> Duration {
+        match self {
+            Grouping::PerQuarterHour => Duration::minutes(15),
+            Grouping::PerDay => Duration::days(1),
+            Grouping::PerWeek => Duration::days(7),
+            Grouping::PerTwoWeeks => Duration::days(14),
+            Grouping::PerMonth => Duration::days(28),
+            Grouping::PerYear => Duration::days(365),
+            Grouping::PerRetail445 => Duration::weeks(4),
+        }
+    }
+}
+
+impl FromStr for Grouping {
+    type Err = IntervalError;
+
+    /// Parse a grouping from its identifier, see [`Grouping::id`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "quarter_hour" => Ok(Grouping::PerQuarterHour),
+            "day" => Ok(Grouping::PerDay),
+            "week" => Ok(Grouping::PerWeek),
+            "two_weeks" => Ok(Grouping::PerTwoWeeks),
+            "month" => Ok(Grouping::PerMonth),
+            "year" => Ok(Grouping::PerYear),
+            "retail_445" => Ok(Grouping::PerRetail445),
+            _ => Err(IntervalError::InvalidGrouping(s.to_owned())),
+        }
+    }
 }
@@ -0,0 +1,30 @@
+//! Time interval with named fields.
+use chrono::{DateTime, TimeZone};
+
+use crate::TimeInterval;
+
+/// Time interval with named fields instead of a tuple.
+///
+/// Converts from and into a [`TimeInterval`] tuple.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interval<T: TimeZone> {
+    /// Beginning of the interval.
+    pub begin: DateTime<T>,
+    /// End of the interval.
+    pub end: DateTime<T>,
+}
+
+impl<T: TimeZone> From<TimeInterval<T>> for Interval<T> {
+    fn from(interval: TimeInterval<T>) -> Self {
+        Interval {
+            begin: interval.0,
+            end: interval.1,
+        }
+    }
+}
+
+impl<T: TimeZone> From<Interval<T>> for TimeInterval<T> {
+    fn from(interval: Interval<T>) -> Self {
+        (interval.begin, interval.end)
+    }
+}
@@ -1,21 +1,42 @@
-use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 
-use crate::{grouping::Grouping, intervals_impl::get_intervals_impl, TimeInterval};
+use crate::{
+    anchor::AnchorMode,
+    grouping::Grouping,
+    intervals_impl::{count_weeks_impl, get_intervals_impl, get_n_intervals_impl},
+    offsets::clamped_offset_west,
+    TimeInterval,
+};
 
 /// Get time intervals with options in the UTC timezone.
 ///
 /// - With `offset_west_seconds`, the intervals boundaries (begin of a day,
 ///   week, month etc.) are shifted towards the west, allowing to retrieve e.g.
-///   day intervals starting on the day boundary in a local timezone.
+///   day intervals starting on the day boundary in a local timezone. The offset
+///   does not need to be whole minutes, e.g. an offset of 30 seconds shifts
+///   the boundaries of all groupings by exactly 30 seconds.
 /// - `end_precision` determines how much time before an interval boundary the
 ///   previous interval ends.
 /// - If `extend_begin` is `true`, the first intervals starts on the interval
 ///   boundary **before** `begin`, otherwise on the interval boundary after it.
 /// - If `extend_end` is `true`, the last intervals ends at the interval
 ///   boundary **after** `end`, otherwise before it.
-pub fn get_utc_intervals_opts<T>(
+/// - If `end` is exactly on an interval boundary, the interval ending right
+///   before `end` is always included. With `extend_end`, the interval starting
+///   at `end` is included as well since it contains `end`. This is the same
+///   for all groupings.
+/// - Without `extend_begin`, no intervals are returned if the first interval
+///   boundary after `begin` is after `end`, even with `extend_end`. The only
+///   candidate interval would start after `end` and not overlap the range.
+/// - If the boundary after the last interval is beyond the maximum date time
+///   supported by `chrono`, the last interval ends at the maximum date time
+///   instead of overflowing.
+/// - `begin` and `end` can be in different timezones.
+/// - An `offset_west_seconds` of a day or more is clamped to just below a day.
+#[must_use]
+pub fn get_utc_intervals_opts<T, V>(
     begin: DateTime<T>,
-    end: DateTime<T>,
+    end: DateTime<V>,
     grouping: &Grouping,
     offset_west_seconds: i32,
     end_precision: Duration,
@@ -24,8 +45,9 @@ pub fn get_utc_intervals_opts<T>(
 ) -> Vec<TimeInterval<Utc>>
 where
     T: TimeZone,
+    V: TimeZone,
 {
-    let local_timezone = &FixedOffset::west(offset_west_seconds);
+    let local_timezone = &clamped_offset_west(offset_west_seconds);
     get_intervals_impl(
         begin,
         end,
@@ -35,6 +57,7 @@ where
         &Utc,
         extend_begin,
         extend_end,
+        &AnchorMode::Boundary,
     )
 }
 
@@ -48,16 +71,19 @@ where
 /// - Interval boundaries are shifted by `offset_west_seconds`. This allows to
 ///   retrieve e.g. daily intervals starting with the days in a specific time
 ///   zone.
-pub fn get_extended_utc_intervals<T>(
+/// - An `offset_west_seconds` of a day or more is clamped to just below a day.
+#[must_use]
+pub fn get_extended_utc_intervals<T, V>(
     begin: DateTime<T>,
-    end: DateTime<T>,
+    end: DateTime<V>,
     grouping: &Grouping,
     offset_west_seconds: i32,
 ) -> Vec<TimeInterval<Utc>>
 where
     T: TimeZone,
+    V: TimeZone,
 {
-    let local_timezone = &FixedOffset::west(offset_west_seconds);
+    let local_timezone = &clamped_offset_west(offset_west_seconds);
     get_intervals_impl(
         begin,
         end,
@@ -67,5 +93,151 @@ where
         &Utc,
         true,
         true,
+        &AnchorMode::Boundary,
     )
 }
+
+/// Get extended time intervals with default options in the UTC timezone.
+///
+/// This is the same as [`get_extended_utc_intervals`] under its older name.
+///
+/// ```rust
+/// use chrono::DateTime;
+/// use chrono_intervals::{
+///     get_extended_utc_intervals, get_extended_utc_intervals_with_defaults, Grouping,
+/// };
+///
+/// let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z").unwrap();
+/// let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z").unwrap();
+///
+/// let daily_intervals = get_extended_utc_intervals(begin, end, &Grouping::PerDay, 0);
+/// assert_eq!(daily_intervals.len(), 3);
+/// assert_eq!(
+///     get_extended_utc_intervals_with_defaults(begin, end, &Grouping::PerDay, 0),
+///     daily_intervals
+/// );
+/// ```
+#[must_use]
+pub fn get_extended_utc_intervals_with_defaults<T>(
+    begin: DateTime<T>,
+    end: DateTime<T>,
+    grouping: &Grouping,
+    offset_west_seconds: i32,
+) -> Vec<TimeInterval<Utc>>
+where
+    T: TimeZone,
+{
+    get_extended_utc_intervals(begin, end, grouping, offset_west_seconds)
+}
+
+/// Count the weekly intervals that [`get_utc_intervals_opts`] returns for
+/// [`Grouping::PerWeek`] with the same options, without generating them.
+///
+/// - Without any extension, only the weeks starting after `begin` and ending
+///   before `end` are counted.
+/// - With `extend_begin`, the week containing `begin` is counted as well.
+/// - With `extend_end`, the week after the last week ending before `end` is
+///   counted as well, this is the week containing `end` unless `end` is
+///   exactly on a week boundary.
+pub fn count_weeks_opts<T>(
+    begin: DateTime<T>,
+    end: DateTime<T>,
+    offset_west_seconds: i32,
+    end_precision: Duration,
+    extend_begin: bool,
+    extend_end: bool,
+) -> usize
+where
+    T: TimeZone,
+{
+    let local_timezone = &clamped_offset_west(offset_west_seconds);
+    count_weeks_impl(
+        begin,
+        end,
+        end_precision,
+        local_timezone,
+        extend_begin,
+        extend_end,
+    )
+}
+
+/// Count the Monday-starting weeks overlapping the range from `begin` to
+/// `end`.
+///
+/// This equals the number of intervals returned by
+/// [`get_extended_utc_intervals`] for [`Grouping::PerWeek`].
+pub fn count_weeks<T>(begin: DateTime<T>, end: DateTime<T>, offset_west_seconds: i32) -> usize
+where
+    T: TimeZone,
+{
+    count_weeks_opts(
+        begin,
+        end,
+        offset_west_seconds,
+        Duration::milliseconds(1),
+        true,
+        true,
+    )
+}
+
+/// Round `timestamp` to the nearest interval boundary of `grouping`.
+///
+/// The boundaries are shifted by `offset_west_seconds` like in
+/// [`get_extended_utc_intervals`]. Of the boundaries before and after
+/// `timestamp`, the closer one is returned and on a tie the one before. With
+/// [`Grouping::PerMonth`], this is the nearer start of a month, so the
+/// midpoint depends on the length of the month.
+pub fn quantize<T>(
+    timestamp: DateTime<T>,
+    grouping: &Grouping,
+    offset_west_seconds: i32,
+) -> DateTime<Utc>
+where
+    T: TimeZone,
+{
+    let timestamp = timestamp.with_timezone(&Utc);
+    let intervals = get_n_intervals_impl(
+        timestamp,
+        2,
+        grouping,
+        Duration::milliseconds(1),
+        &clamped_offset_west(offset_west_seconds),
+        &Utc,
+        true,
+        &AnchorMode::Boundary,
+    );
+    let (boundary_before, boundary_after) = (intervals[0].0, intervals[1].0);
+    match timestamp - boundary_before <= boundary_after - timestamp {
+        true => boundary_before,
+        false => boundary_after,
+    }
+}
+
+/// Suggest the grouping giving the number of intervals closest to
+/// `target_buckets` for the range from `begin` to `end`.
+///
+/// Only [`Grouping::PerDay`], [`Grouping::PerWeek`], [`Grouping::PerMonth`]
+/// and [`Grouping::PerYear`] are considered. The number of intervals is
+/// estimated from the average length of the intervals, e.g. 365.25 days per
+/// year. On a tie, the finer grouping is suggested.
+pub fn suggest_grouping<T>(begin: DateTime<T>, end: DateTime<T>, target_buckets: usize) -> Grouping
+where
+    T: TimeZone,
+{
+    let span_days = (end - begin).num_seconds() as f64 / Duration::days(1).num_seconds() as f64;
+    let bucket_count_error =
+        |average_days: f64| (span_days.max(0.0) / average_days - target_buckets as f64).abs();
+
+    let mut suggestion = (Grouping::PerDay, bucket_count_error(1.0));
+    for (grouping, average_days) in [
+        (Grouping::PerWeek, 7.0),
+        (Grouping::PerMonth, 365.25 / 12.0),
+        (Grouping::PerYear, 365.25),
+    ] {
+        let error = bucket_count_error(average_days);
+        if error < suggestion.1 {
+            suggestion = (grouping, error);
+        }
+    }
+    suggestion.0
+}
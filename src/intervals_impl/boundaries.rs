@@ -1,107 +1,897 @@
-use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone};
+use std::panic::RefUnwindSafe;
 
-pub fn get_initial_begin_end_times_day<T>(
-    begin: DateTime<T>,
-    local_timezone: &FixedOffset,
-    end_precision: Duration,
-    extend_begin: bool,
-) -> (DateTime<FixedOffset>, DateTime<FixedOffset>)
-where
-    T: TimeZone,
-{
-    let init_begin = match extend_begin {
-        true => begin.with_timezone(local_timezone).date().and_hms(0, 0, 0),
-        false => begin.with_timezone(local_timezone).date().and_hms(0, 0, 0) + Duration::hours(24),
-    };
-    let init_end = init_begin + Duration::hours(24) - end_precision;
-    (init_begin, init_end)
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeZone, Timelike, Utc,
+};
+
+use crate::{strategy::GroupingStrategy, TimeInterval};
+
+/// Rules of a built-in grouping beyond stepping forward.
+pub trait GroupingRules: GroupingStrategy {
+    /// Get the interval before the one starting at `cur_begin`.
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset>;
+
+    /// Get the beginning of the interval `n` periods after `anchor`.
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>>;
+
+    /// Get the index of the anchored interval containing `begin`.
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64;
 }
 
-pub fn get_initial_begin_end_times_week<T>(
-    begin: DateTime<T>,
-    local_timezone: &FixedOffset,
-    end_precision: Duration,
-    extend_begin: bool,
-) -> (DateTime<FixedOffset>, DateTime<FixedOffset>)
+pub struct QuarterHour;
+pub struct Day;
+pub struct Week;
+pub struct TwoWeeks;
+pub struct Month;
+pub struct Year;
+pub struct Retail445;
+
+/// Weeks of a 4-4-5 retail year before each of its periods.
+const RETAIL_445_WEEKS_BEFORE: [i64; 12] = [0, 4, 8, 13, 17, 21, 26, 30, 34, 39, 43, 47];
+
+/// Consecutive windows of 28 days starting at `begin`.
+pub struct EqualLengthMonth;
+
+/// Windows of length `window` starting `step` after each other.
+///
+/// The windows overlap if `step` is shorter than `window`.
+pub struct SlidingWindow {
+    pub window: Duration,
+    pub step: Duration,
+}
+
+/// Calendar days in a timezone with daylight saving time.
+///
+/// A day runs from midnight to midnight in `timezone`, which is 23 or 25
+/// hours long on the days the offset changes.
+pub struct WallClockDay<Tz> {
+    pub timezone: Tz,
+}
+
+impl GroupingStrategy for QuarterHour {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let localized_begin = clamped_naive_local(&begin.with_timezone(local_timezone));
+        let quarter_hour_begin = |quarters_ahead: i64| {
+            let begin = localized_begin
+                .date()
+                .and_hms_opt(
+                    localized_begin.hour(),
+                    localized_begin.minute() / 15 * 15,
+                    0,
+                )?
+                .checked_add_signed(Duration::minutes(15 * quarters_ahead))?;
+            local_boundary(begin.date(), begin.time(), local_timezone)
+        };
+        // Near the minimum date time, the extended interval can begin out of
+        // range, so the intervals start with the next one
+        let init_begin = match extend_begin {
+            true => quarter_hour_begin(0).or_else(|| quarter_hour_begin(1)),
+            false => quarter_hour_begin(1),
+        };
+        let init_begin = get_begin_time(init_begin, local_timezone);
+        get_fixed_period_times(init_begin, Duration::minutes(15), end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(
+            get_begin_time(
+                cur_begin.checked_add_signed(Duration::minutes(15)),
+                &cur_begin.timezone(),
+            ),
+            Duration::minutes(15),
+            end_precision,
+        )
+    }
+}
+
+impl GroupingRules for QuarterHour {
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        (
+            get_previous_begin_time(
+                cur_begin.checked_sub_signed(Duration::minutes(15)),
+                &cur_begin.timezone(),
+            ),
+            get_previous_end_time(cur_begin, end_precision),
+        )
+    }
+
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>> {
+        anchor.checked_add_signed(Duration::minutes(15 * n))
+    }
+
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64 {
+        get_fixed_period_index(anchor, begin, Duration::minutes(15))
+    }
+}
+
+impl GroupingStrategy for Day {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let localized_date = clamped_naive_local(&begin.with_timezone(local_timezone)).date();
+        let next_day_begin = || local_day_start(localized_date.succ_opt()?, local_timezone);
+        let init_begin = match extend_begin {
+            true => local_day_start(localized_date, local_timezone).or_else(next_day_begin),
+            false => next_day_begin(),
+        };
+        let init_begin = get_begin_time(init_begin, local_timezone);
+        get_fixed_period_times(init_begin, Duration::hours(24), end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(
+            get_begin_time(
+                cur_begin.checked_add_signed(Duration::hours(24)),
+                &cur_begin.timezone(),
+            ),
+            Duration::hours(24),
+            end_precision,
+        )
+    }
+}
+
+impl GroupingStrategy for EqualLengthMonth {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        _local_timezone: &FixedOffset,
+        end_precision: Duration,
+        _extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(begin, Duration::days(28), end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(
+            get_begin_time(
+                cur_begin.checked_add_signed(Duration::days(28)),
+                &cur_begin.timezone(),
+            ),
+            Duration::days(28),
+            end_precision,
+        )
+    }
+}
+
+impl GroupingStrategy for SlidingWindow {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        _local_timezone: &FixedOffset,
+        end_precision: Duration,
+        _extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(begin, self.window, end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(
+            get_begin_time(
+                cur_begin.checked_add_signed(self.step),
+                &cur_begin.timezone(),
+            ),
+            self.window,
+            end_precision,
+        )
+    }
+}
+
+impl<Tz> GroupingStrategy for WallClockDay<Tz>
 where
-    T: TimeZone,
+    Tz: TimeZone + Send + Sync + RefUnwindSafe,
 {
-    let localized_begin = begin.with_timezone(local_timezone);
-    let num_days_since_monday = localized_begin.weekday() as i64;
-    let init_begin = match extend_begin {
-        true => localized_begin.date().and_hms(0, 0, 0) - Duration::days(num_days_since_monday),
-        false => {
-            localized_begin.date().and_hms(0, 0, 0) + Duration::days(7 - num_days_since_monday)
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        _local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let date = clamped_naive_local(&begin.with_timezone(&self.timezone)).date();
+        let init_date = match extend_begin {
+            true => Some(date),
+            false => date.succ_opt(),
+        };
+        self.day_times(init_date, &begin.timezone(), end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        let date = clamped_naive_local(&cur_begin.with_timezone(&self.timezone)).date();
+        self.day_times(date.succ_opt(), &cur_begin.timezone(), end_precision)
+    }
+}
+
+impl<Tz: TimeZone> WallClockDay<Tz> {
+    /// Get the day `date`, saturating at the maximum date time if it is out
+    /// of range like the other rules.
+    fn day_times(
+        &self,
+        date: Option<NaiveDate>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        let begin = get_begin_time(date.and_then(|date| self.day_start(date)), local_timezone);
+        let end = get_end_time(
+            date.and_then(|date| self.day_start(date.succ_opt()?)),
+            &begin.timezone(),
+            end_precision,
+        );
+        (begin, end)
+    }
+
+    /// Get the first instant of `date` in the timezone.
+    ///
+    /// If midnight is skipped by a change of the offset, the day starts at
+    /// the first full hour after midnight that exists.
+    fn day_start(&self, date: NaiveDate) -> Option<DateTime<FixedOffset>> {
+        (0..24).find_map(|hour| {
+            let day_start = self
+                .timezone
+                .from_local_datetime(&date.and_hms_opt(hour, 0, 0)?)
+                .earliest()?;
+            Some(day_start.with_timezone(&day_start.offset().fix()))
+        })
+    }
+}
+
+impl GroupingRules for Day {
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        (
+            get_previous_begin_time(
+                cur_begin.checked_sub_signed(Duration::hours(24)),
+                &cur_begin.timezone(),
+            ),
+            get_previous_end_time(cur_begin, end_precision),
+        )
+    }
+
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>> {
+        anchor.checked_add_signed(Duration::days(n))
+    }
+
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64 {
+        get_fixed_period_index(anchor, begin, Duration::days(1))
+    }
+}
+
+impl GroupingStrategy for Week {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let localized_date = clamped_naive_local(&begin.with_timezone(local_timezone)).date();
+        let num_days_since_monday = localized_date.weekday().num_days_from_monday() as i64;
+        let week_begin = |days_ahead: i64| {
+            local_day_start(
+                localized_date.checked_add_signed(Duration::days(days_ahead))?,
+                local_timezone,
+            )
+        };
+        let init_begin = match extend_begin {
+            true => {
+                week_begin(-num_days_since_monday).or_else(|| week_begin(7 - num_days_since_monday))
+            }
+            false => week_begin(7 - num_days_since_monday),
+        };
+        let init_begin = get_begin_time(init_begin, local_timezone);
+        get_fixed_period_times(init_begin, Duration::days(7), end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(
+            get_begin_time(
+                cur_begin.checked_add_signed(Duration::days(7)),
+                &cur_begin.timezone(),
+            ),
+            Duration::days(7),
+            end_precision,
+        )
+    }
+}
+
+impl GroupingRules for Week {
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        (
+            get_previous_begin_time(
+                cur_begin.checked_sub_signed(Duration::days(7)),
+                &cur_begin.timezone(),
+            ),
+            get_previous_end_time(cur_begin, end_precision),
+        )
+    }
+
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>> {
+        anchor.checked_add_signed(Duration::days(7 * n))
+    }
+
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64 {
+        get_fixed_period_index(anchor, begin, Duration::days(7))
+    }
+}
+
+impl GroupingStrategy for TwoWeeks {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let (init_begin, _) = Week.initial(begin, local_timezone, end_precision, extend_begin);
+        get_fixed_period_times(init_begin, Duration::days(14), end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        get_fixed_period_times(
+            get_begin_time(
+                cur_begin.checked_add_signed(Duration::days(14)),
+                &cur_begin.timezone(),
+            ),
+            Duration::days(14),
+            end_precision,
+        )
+    }
+}
+
+impl GroupingRules for TwoWeeks {
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        (
+            get_previous_begin_time(
+                cur_begin.checked_sub_signed(Duration::days(14)),
+                &cur_begin.timezone(),
+            ),
+            get_previous_end_time(cur_begin, end_precision),
+        )
+    }
+
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>> {
+        anchor.checked_add_signed(Duration::days(14 * n))
+    }
+
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64 {
+        get_fixed_period_index(anchor, begin, Duration::days(14))
+    }
+}
+
+impl GroupingStrategy for Month {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let localized_date = clamped_naive_local(&begin.with_timezone(local_timezone)).date();
+        let months_ahead = match extend_begin
+            && local_month_start(
+                localized_date.year(),
+                localized_date.month0(),
+                local_timezone,
+            )
+            .is_some()
+        {
+            true => 0,
+            false => 1,
+        };
+        get_month_times(
+            localized_date.year(),
+            localized_date.month0() + months_ahead,
+            local_timezone,
+            end_precision,
+        )
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        let cur_date = clamped_naive_local(&cur_begin).date();
+        get_month_times(
+            cur_date.year(),
+            cur_date.month0() + 1,
+            &cur_begin.timezone(),
+            end_precision,
+        )
+    }
+}
+
+impl GroupingRules for Month {
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        (
+            get_previous_begin_time(previous_month_start(cur_begin), &cur_begin.timezone()),
+            get_previous_end_time(cur_begin, end_precision),
+        )
+    }
+
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>> {
+        match n >= 0 {
+            true => anchor.checked_add_months(Months::new(u32::try_from(n).ok()?)),
+            false => anchor.checked_sub_months(Months::new(u32::try_from(-n).ok()?)),
         }
-    };
-    let init_end = init_begin + Duration::days(7) - end_precision;
-    (init_begin, init_end)
+    }
+
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64 {
+        let (begin_date, anchor_date) = (
+            clamped_naive_local(&begin).date(),
+            clamped_naive_local(&anchor).date(),
+        );
+        let num_months = (begin_date.year() - anchor_date.year()) as i64 * 12
+            + begin_date.month() as i64
+            - anchor_date.month() as i64;
+        match self.nth_anchored_begin(anchor, num_months) {
+            Some(nth_begin) if nth_begin > begin => num_months - 1,
+            _ => num_months,
+        }
+    }
+}
+
+impl GroupingStrategy for Year {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let localized_begin = begin.with_timezone(local_timezone);
+        let init_begin = match extend_begin {
+            true => get_previous_begin_time(local_year_start(localized_begin), local_timezone),
+            false => get_begin_time(next_year_start(localized_begin), local_timezone),
+        };
+        let init_end = get_end_time(
+            next_year_start(init_begin),
+            &init_begin.timezone(),
+            end_precision,
+        );
+        (init_begin, init_end)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        let cur_begin = get_begin_time(next_year_start(cur_begin), &cur_begin.timezone());
+        let cur_end = get_end_time(
+            next_year_start(cur_begin),
+            &cur_begin.timezone(),
+            end_precision,
+        );
+        (cur_begin, cur_end)
+    }
+}
+
+impl GroupingRules for Year {
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        (
+            get_previous_begin_time(previous_year_start(cur_begin), &cur_begin.timezone()),
+            get_previous_end_time(cur_begin, end_precision),
+        )
+    }
+
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>> {
+        Month.nth_anchored_begin(anchor, 12 * n)
+    }
+
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64 {
+        let num_years =
+            (clamped_naive_local(&begin).year() - clamped_naive_local(&anchor).year()) as i64;
+        match self.nth_anchored_begin(anchor, num_years) {
+            Some(nth_begin) if nth_begin > begin => num_years - 1,
+            _ => num_years,
+        }
+    }
+}
+
+impl GroupingStrategy for Retail445 {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let localized_date = clamped_naive_local(&begin.with_timezone(local_timezone)).date();
+        let period = get_local_retail_period_index(localized_date);
+        let init_period = match extend_begin
+            && local_retail_period_start(localized_date.year(), period, local_timezone).is_some()
+        {
+            true => period,
+            false => period + 1,
+        };
+        get_retail_period_times(
+            localized_date.year(),
+            init_period,
+            local_timezone,
+            end_precision,
+        )
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        let cur_date = clamped_naive_local(&cur_begin).date();
+        get_retail_period_times(
+            cur_date.year(),
+            get_local_retail_period_index(cur_date) + 1,
+            &cur_begin.timezone(),
+            end_precision,
+        )
+    }
 }
 
-pub fn get_initial_begin_end_times_month<T>(
-    begin: DateTime<T>,
+impl GroupingRules for Retail445 {
+    fn previous(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        let cur_date = clamped_naive_local(&cur_begin).date();
+        (
+            get_previous_begin_time(
+                local_retail_period_start(
+                    cur_date.year(),
+                    get_local_retail_period_index(cur_date) - 1,
+                    &cur_begin.timezone(),
+                ),
+                &cur_begin.timezone(),
+            ),
+            get_previous_end_time(cur_begin, end_precision),
+        )
+    }
+
+    fn nth_anchored_begin(
+        &self,
+        anchor: DateTime<FixedOffset>,
+        n: i64,
+    ) -> Option<DateTime<FixedOffset>> {
+        let year_start = Year.nth_anchored_begin(anchor, n.div_euclid(12))?;
+        year_start.checked_add_signed(Duration::weeks(
+            RETAIL_445_WEEKS_BEFORE[n.rem_euclid(12) as usize],
+        ))
+    }
+
+    fn anchored_index(&self, anchor: DateTime<FixedOffset>, begin: DateTime<FixedOffset>) -> i64 {
+        let num_years = Year.anchored_index(anchor, begin);
+        match Year.nth_anchored_begin(anchor, num_years) {
+            Some(year_start) => {
+                num_years * 12 + get_retail_period_index((begin - year_start).num_weeks())
+            }
+            None => num_years * 12,
+        }
+    }
+}
+
+/// Get the end of the interval before `next_begin`.
+///
+/// If `next_begin` is out of range, the end saturates at the maximum date time
+/// instead of overflowing.
+pub fn get_end_time(
+    next_begin: Option<DateTime<FixedOffset>>,
     local_timezone: &FixedOffset,
     end_precision: Duration,
-    extend_begin: bool,
-) -> (DateTime<FixedOffset>, DateTime<FixedOffset>)
-where
-    T: TimeZone,
-{
-    let localized_begin = begin.with_timezone(local_timezone);
-    let init_begin = match extend_begin {
-        true => local_timezone
-            .ymd(localized_begin.year(), localized_begin.month(), 1)
-            .and_hms(0, 0, 0),
-        false => next_month_start(localized_begin),
-    };
-    let init_end = next_month_start(init_begin) - end_precision;
-    (init_begin, init_end)
+) -> DateTime<FixedOffset> {
+    match next_begin {
+        Some(next_begin) => get_previous_end_time(next_begin, end_precision),
+        None => DateTime::<Utc>::MAX_UTC.with_timezone(local_timezone),
+    }
 }
 
-pub fn get_next_begin_end_times_day(
+/// Get the end of the interval before the one starting at `cur_begin`,
+/// saturating at the minimum date time if it is out of range.
+fn get_previous_end_time(
     cur_begin: DateTime<FixedOffset>,
     end_precision: Duration,
-) -> (DateTime<FixedOffset>, DateTime<FixedOffset>) {
-    let cur_begin = cur_begin + Duration::hours(24);
-    let cur_end = cur_begin + Duration::hours(24) - end_precision;
-    (cur_begin, cur_end)
+) -> DateTime<FixedOffset> {
+    get_previous_begin_time(
+        cur_begin.checked_sub_signed(end_precision),
+        &cur_begin.timezone(),
+    )
 }
 
-pub fn get_next_begin_end_times_week(
-    cur_begin: DateTime<FixedOffset>,
-    end_precision: Duration,
-) -> (DateTime<FixedOffset>, DateTime<FixedOffset>) {
-    let cur_begin = cur_begin + Duration::days(7);
-    let cur_end = cur_begin + Duration::days(7) - end_precision;
-    (cur_begin, cur_end)
+/// Get the beginning of an interval after the current one.
+///
+/// If `begin` is out of range, it saturates at the maximum date time like
+/// the end in [`get_end_time`], so no interval after it is generated.
+pub fn get_begin_time(
+    begin: Option<DateTime<FixedOffset>>,
+    local_timezone: &FixedOffset,
+) -> DateTime<FixedOffset> {
+    begin.unwrap_or_else(|| DateTime::<Utc>::MAX_UTC.with_timezone(local_timezone))
 }
 
-pub fn get_next_begin_end_times_month(
-    cur_begin: DateTime<FixedOffset>,
+/// Get the beginning of an interval before the current one, saturating at
+/// the minimum date time if it is out of range.
+pub fn get_previous_begin_time(
+    begin: Option<DateTime<FixedOffset>>,
+    local_timezone: &FixedOffset,
+) -> DateTime<FixedOffset> {
+    begin.unwrap_or_else(|| DateTime::<Utc>::MIN_UTC.with_timezone(local_timezone))
+}
+
+/// Get the interval of length `period` starting at `begin`.
+fn get_fixed_period_times(
+    begin: DateTime<FixedOffset>,
+    period: Duration,
     end_precision: Duration,
-) -> (DateTime<FixedOffset>, DateTime<FixedOffset>) {
-    let cur_begin = next_month_start(cur_begin);
-    let cur_end = next_month_start(cur_begin) - end_precision;
-    (cur_begin, cur_end)
+) -> TimeInterval<FixedOffset> {
+    let end = get_end_time(
+        begin.checked_add_signed(period),
+        &begin.timezone(),
+        end_precision,
+    );
+    (begin, end)
 }
 
-fn next_month_start<T>(datetime: DateTime<T>) -> DateTime<T>
-where
-    T: TimeZone,
-{
-    let date = datetime.date();
+/// Get the index of the interval of length `period` containing `begin`.
+fn get_fixed_period_index(
+    anchor: DateTime<FixedOffset>,
+    begin: DateTime<FixedOffset>,
+    period: Duration,
+) -> i64 {
+    // Round the difference down to whole seconds, periods are whole seconds
+    let diff = begin - anchor;
+    let mut diff_secs = diff.num_seconds();
+    if diff < Duration::seconds(diff_secs) {
+        diff_secs -= 1;
+    }
+    diff_secs.div_euclid(period.num_seconds())
+}
+
+/// Get the local date time of `datetime`, clamped to the range of
+/// `NaiveDateTime`.
+///
+/// Near the minimum and maximum date time, the local time can be out of range
+/// and converting it would panic.
+pub fn clamped_naive_local<Tz: TimeZone>(datetime: &DateTime<Tz>) -> NaiveDateTime {
+    let offset = Duration::seconds(datetime.offset().fix().local_minus_utc().into());
     datetime
-        .timezone()
-        .ymd(
-            match date.month() {
-                12 => date.year() + 1,
-                _ => date.year(),
-            },
-            match date.month() {
-                12 => 1,
-                _ => date.month() + 1,
-            },
-            1,
-        )
-        .and_hms(0, 0, 0)
+        .naive_utc()
+        .checked_add_signed(offset)
+        .unwrap_or(match offset > Duration::zero() {
+            true => NaiveDateTime::MAX,
+            false => NaiveDateTime::MIN,
+        })
+}
+
+/// Get the boundary at `time` on `date` in the local timezone.
+///
+/// All interval boundaries are derived from this so that the offset is applied
+/// in the same way for all groupings. Returns `None` if the boundary is out of
+/// range.
+fn local_boundary(
+    date: NaiveDate,
+    time: NaiveTime,
+    local_timezone: &FixedOffset,
+) -> Option<DateTime<FixedOffset>> {
+    local_timezone
+        .from_local_datetime(&date.and_time(time))
+        .single()
+}
+
+fn local_day_start(date: NaiveDate, local_timezone: &FixedOffset) -> Option<DateTime<FixedOffset>> {
+    local_boundary(date, NaiveTime::MIN, local_timezone)
+}
+
+/// Get the start of the year of `datetime` in its timezone.
+fn local_year_start(datetime: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+    local_day_start(
+        NaiveDate::from_ymd_opt(clamped_naive_local(&datetime).date().year(), 1, 1)?,
+        &datetime.timezone(),
+    )
+}
+
+/// Get the index of the 4-4-5 retail period starting `num_weeks` whole weeks
+/// after the start of its year.
+///
+/// The last period extends to the start of the next year, since a year is
+/// one or two days longer than its 52 weeks.
+fn get_retail_period_index(num_weeks: i64) -> i64 {
+    RETAIL_445_WEEKS_BEFORE.partition_point(|&weeks_before| weeks_before <= num_weeks) as i64 - 1
+}
+
+/// Get the index of the 4-4-5 retail period containing `date` in its year.
+fn get_local_retail_period_index(date: NaiveDate) -> i64 {
+    get_retail_period_index(date.ordinal0() as i64 / 7)
+}
+
+/// Get the start of the 4-4-5 retail period `period` periods after the start
+/// of `year`.
+///
+/// Like [`local_month_start`], the boundary is computed from the local date so
+/// that it does not depend on an earlier boundary being in range.
+fn local_retail_period_start(
+    year: i32,
+    period: i64,
+    local_timezone: &FixedOffset,
+) -> Option<DateTime<FixedOffset>> {
+    let year = year.checked_add(i32::try_from(period.div_euclid(12)).ok()?)?;
+    let weeks_before = RETAIL_445_WEEKS_BEFORE[period.rem_euclid(12) as usize];
+    local_day_start(
+        NaiveDate::from_ymd_opt(year, 1, 1)?.checked_add_signed(Duration::weeks(weeks_before))?,
+        local_timezone,
+    )
+}
+
+/// Get the 4-4-5 retail period `period` periods after the start of `year`.
+fn get_retail_period_times(
+    year: i32,
+    period: i64,
+    local_timezone: &FixedOffset,
+    end_precision: Duration,
+) -> TimeInterval<FixedOffset> {
+    let begin = get_begin_time(
+        local_retail_period_start(year, period, local_timezone),
+        local_timezone,
+    );
+    let end = get_end_time(
+        local_retail_period_start(year, period + 1, local_timezone),
+        local_timezone,
+        end_precision,
+    );
+    (begin, end)
+}
+
+/// Get the start of the month `month0` months after January of `year`.
+///
+/// `month0` can exceed 11 to get the start of a month in a later year.
+fn local_month_start(
+    year: i32,
+    month0: u32,
+    local_timezone: &FixedOffset,
+) -> Option<DateTime<FixedOffset>> {
+    let year = year.checked_add(i32::try_from(month0 / 12).ok()?)?;
+    local_day_start(
+        NaiveDate::from_ymd_opt(year, month0 % 12 + 1, 1)?,
+        local_timezone,
+    )
+}
+
+/// Get the monthly interval starting `month0` months after January of
+/// `year`.
+///
+/// Both boundaries are computed from the year and month without converting
+/// the beginning back to a date for the end.
+pub fn get_month_times(
+    year: i32,
+    month0: u32,
+    local_timezone: &FixedOffset,
+    end_precision: Duration,
+) -> TimeInterval<FixedOffset> {
+    let begin = get_begin_time(
+        local_month_start(year, month0, local_timezone),
+        local_timezone,
+    );
+    let end = get_end_time(
+        local_month_start(year, month0 + 1, local_timezone),
+        local_timezone,
+        end_precision,
+    );
+    (begin, end)
+}
+
+fn previous_month_start(datetime: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+    let date = clamped_naive_local(&datetime).date();
+    let previous_month_date = match date.month() {
+        1 => NaiveDate::from_ymd_opt(date.year() - 1, 12, 1)?,
+        _ => NaiveDate::from_ymd_opt(date.year(), date.month() - 1, 1)?,
+    };
+    local_day_start(previous_month_date, &datetime.timezone())
+}
+
+fn next_year_start(datetime: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+    let date = clamped_naive_local(&datetime).date();
+    local_day_start(
+        NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)?,
+        &datetime.timezone(),
+    )
+}
+
+fn previous_year_start(datetime: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+    let date = clamped_naive_local(&datetime).date();
+    local_day_start(
+        NaiveDate::from_ymd_opt(date.year() - 1, 1, 1)?,
+        &datetime.timezone(),
+    )
 }
@@ -2,67 +2,651 @@
 mod boundaries;
 
 use boundaries::{
-    get_initial_begin_end_times_day, get_initial_begin_end_times_month,
-    get_initial_begin_end_times_week, get_next_begin_end_times_day, get_next_begin_end_times_month,
-    get_next_begin_end_times_week,
+    clamped_naive_local, get_begin_time, get_end_time, get_month_times, get_previous_begin_time,
+    Day, GroupingRules, Month, QuarterHour, Retail445, TwoWeeks, Week, Year,
 };
-use chrono::{DateTime, Duration, FixedOffset, TimeZone};
+pub use boundaries::{EqualLengthMonth, SlidingWindow, WallClockDay};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone};
 
-use crate::{grouping::Grouping, TimeInterval};
+use crate::{anchor::AnchorMode, grouping::Grouping, strategy::GroupingStrategy, TimeInterval};
 
 #[allow(clippy::too_many_arguments)]
-pub fn get_intervals_impl<T, U>(
+pub fn get_intervals_impl<T, V, U>(
     begin: DateTime<T>,
-    end: DateTime<T>,
+    end: DateTime<V>,
     grouping: &Grouping,
     end_precision: Duration,
     local_timezone: &FixedOffset,
     output_timezone: &U,
     extend_begin: bool,
     extend_end: bool,
+    anchor_mode: &AnchorMode,
 ) -> Vec<TimeInterval<U>>
 where
     T: TimeZone,
+    V: TimeZone,
+    U: TimeZone,
+{
+    IntervalIter::new(
+        begin,
+        end,
+        grouping,
+        end_precision,
+        local_timezone,
+        output_timezone,
+        extend_begin,
+        extend_end,
+        anchor_mode,
+    )
+    .collect()
+}
+
+/// Get the first interval that [`get_intervals_impl`] would return without
+/// stepping over the following ones.
+#[allow(clippy::too_many_arguments)]
+pub fn get_first_interval_impl<T, V, U>(
+    begin: DateTime<T>,
+    end: DateTime<V>,
+    grouping: &Grouping,
+    end_precision: Duration,
+    local_timezone: &FixedOffset,
+    output_timezone: &U,
+    extend_begin: bool,
+    extend_end: bool,
+    anchor_mode: &AnchorMode,
+) -> Option<TimeInterval<U>>
+where
+    T: TimeZone,
+    V: TimeZone,
     U: TimeZone,
 {
+    let begin = begin.with_timezone(local_timezone);
+    let end = end.with_timezone(local_timezone);
     if begin >= end {
-        return Vec::with_capacity(0);
+        return None;
     }
 
-    let mut intervals = Vec::new();
-    let (mut cur_begin, mut cur_end) = match grouping {
-        Grouping::PerDay => {
-            get_initial_begin_end_times_day(begin, local_timezone, end_precision, extend_begin)
-        }
-        Grouping::PerWeek => {
-            get_initial_begin_end_times_week(begin, local_timezone, end_precision, extend_begin)
+    let first = IntervalStepper::new(
+        begin,
+        grouping,
+        end_precision,
+        local_timezone,
+        extend_begin,
+        anchor_mode,
+    )
+    .step();
+    match first.1 < end || (extend_end && first.0 <= end) {
+        true => Some(to_output_timezone(first, output_timezone)),
+        false => None,
+    }
+}
+
+/// Get the last interval that [`get_intervals_impl`] would return by snapping
+/// to the boundaries around `end` instead of stepping from `begin`.
+#[allow(clippy::too_many_arguments)]
+pub fn get_last_interval_impl<T, V, U>(
+    begin: DateTime<T>,
+    end: DateTime<V>,
+    grouping: &Grouping,
+    end_precision: Duration,
+    local_timezone: &FixedOffset,
+    output_timezone: &U,
+    extend_begin: bool,
+    extend_end: bool,
+    anchor_mode: &AnchorMode,
+) -> Option<TimeInterval<U>>
+where
+    T: TimeZone,
+    V: TimeZone,
+    U: TimeZone,
+{
+    let begin = begin.with_timezone(local_timezone);
+    let end = end.with_timezone(local_timezone);
+    if begin >= end {
+        return None;
+    }
+
+    let first = IntervalStepper::new(
+        begin,
+        grouping,
+        end_precision,
+        local_timezone,
+        extend_begin,
+        anchor_mode,
+    )
+    .step();
+    if first.0 > end {
+        return None;
+    }
+
+    // Near the maximum date time, the local time of `end` can be out of range
+    // east of UTC, so step from `begin` instead of snapping around `end`.
+    let local_offset = Duration::seconds(local_timezone.local_minus_utc().into());
+    if end.naive_utc().checked_add_signed(local_offset).is_none() {
+        return IntervalIter::new(
+            begin,
+            end,
+            grouping,
+            end_precision,
+            local_timezone,
+            output_timezone,
+            extend_begin,
+            extend_end,
+            anchor_mode,
+        )
+        .last();
+    }
+
+    // The first interval not ending before `end` and the one before it. If
+    // `end` is in the gap of `end_precision`, the enclosing interval is the
+    // one before `end`.
+    let rules = get_rules(grouping);
+    let anchor = match (anchor_mode, grouping) {
+        // Two weeks are counted from the first week of the range
+        (AnchorMode::Boundary, Grouping::PerTwoWeeks) => Some(first.0),
+        (AnchorMode::Boundary, _) => None,
+        (AnchorMode::Begin, _) => Some(begin),
+        (AnchorMode::At(anchor), _) => Some(anchor.with_timezone(local_timezone)),
+    };
+    let (at_end, before_end) = match anchor {
+        None => {
+            let mut at_end = rules.initial(end, local_timezone, end_precision, true);
+            if at_end.1 < end {
+                at_end = rules.next(at_end.0, end_precision);
+            }
+            (at_end, rules.previous(at_end.0, end_precision))
         }
-        Grouping::PerMonth => {
-            get_initial_begin_end_times_month(begin, local_timezone, end_precision, extend_begin)
+        Some(anchor) => {
+            let mut index = rules.anchored_index(anchor, end);
+            if get_anchored_begin_end_times(anchor, rules, index, end_precision).1 < end {
+                index += 1;
+            }
+            (
+                get_anchored_begin_end_times(anchor, rules, index, end_precision),
+                get_anchored_begin_end_times(anchor, rules, index - 1, end_precision),
+            )
         }
     };
 
-    while cur_end < end {
-        intervals.push((cur_begin, cur_end));
+    let last = match extend_end {
+        true if at_end.0 < first.0 => first,
+        true => at_end,
+        false => before_end,
+    };
+    match last.0 >= first.0 {
+        true => Some(to_output_timezone(last, output_timezone)),
+        false => None,
+    }
+}
 
-        (cur_begin, cur_end) = match grouping {
-            Grouping::PerDay => get_next_begin_end_times_day(cur_begin, end_precision),
-            Grouping::PerWeek => get_next_begin_end_times_week(cur_begin, end_precision),
-            Grouping::PerMonth => get_next_begin_end_times_month(cur_begin, end_precision),
-        }
+#[allow(clippy::too_many_arguments)]
+pub fn get_n_intervals_impl<T, U>(
+    begin: DateTime<T>,
+    count: usize,
+    grouping: &Grouping,
+    end_precision: Duration,
+    local_timezone: &FixedOffset,
+    output_timezone: &U,
+    extend_begin: bool,
+    anchor_mode: &AnchorMode,
+) -> Vec<TimeInterval<U>>
+where
+    T: TimeZone,
+    U: TimeZone,
+{
+    if count == 0 {
+        return Vec::with_capacity(0);
     }
 
-    if extend_end {
-        intervals.push((cur_begin, cur_end));
+    let mut intervals = Vec::with_capacity(count);
+    let mut stepper = IntervalStepper::new(
+        begin,
+        grouping,
+        end_precision,
+        local_timezone,
+        extend_begin,
+        anchor_mode,
+    );
+    let (mut cur_begin, mut cur_end) = stepper.step();
+    intervals.push(to_output_timezone((cur_begin, cur_end), output_timezone));
+
+    while intervals.len() < count {
+        (cur_begin, cur_end) = stepper.step();
+        intervals.push(to_output_timezone((cur_begin, cur_end), output_timezone));
     }
 
     intervals
-        .into_iter()
-        .map(|interval| {
-            (
-                interval.0.with_timezone(output_timezone),
-                interval.1.with_timezone(output_timezone),
-            )
-        })
+}
+
+pub fn get_n_strategy_intervals_impl<T, U>(
+    begin: DateTime<T>,
+    count: usize,
+    strategy: &dyn GroupingStrategy,
+    end_precision: Duration,
+    local_timezone: &FixedOffset,
+    output_timezone: &U,
+    extend_begin: bool,
+) -> Vec<TimeInterval<U>>
+where
+    T: TimeZone,
+    U: TimeZone,
+{
+    let mut stepper = StrategyStepper::new(
+        begin.with_timezone(local_timezone),
+        strategy,
+        end_precision,
+        local_timezone,
+        extend_begin,
+    );
+    (0..count)
+        .map(|_| to_output_timezone(stepper.step(), output_timezone))
         .collect()
 }
+
+pub fn get_previous_interval_impl<T, U>(
+    begin: DateTime<T>,
+    grouping: &Grouping,
+    end_precision: Duration,
+    local_timezone: &FixedOffset,
+    output_timezone: &U,
+) -> TimeInterval<U>
+where
+    T: TimeZone,
+    U: TimeZone,
+{
+    let rules = get_rules(grouping);
+    let (enclosing_begin, _) = rules.initial(
+        begin.with_timezone(local_timezone),
+        local_timezone,
+        end_precision,
+        true,
+    );
+    let (previous_begin, previous_end) = rules.previous(enclosing_begin, end_precision);
+
+    to_output_timezone((previous_begin, previous_end), output_timezone)
+}
+
+pub fn get_next_interval_impl<T, U>(
+    end: DateTime<T>,
+    grouping: &Grouping,
+    end_precision: Duration,
+    local_timezone: &FixedOffset,
+    output_timezone: &U,
+) -> TimeInterval<U>
+where
+    T: TimeZone,
+    U: TimeZone,
+{
+    let rules = get_rules(grouping);
+    let (enclosing_begin, _) = rules.initial(
+        end.with_timezone(local_timezone),
+        local_timezone,
+        end_precision,
+        true,
+    );
+    let (next_begin, next_end) = rules.next(enclosing_begin, end_precision);
+
+    to_output_timezone((next_begin, next_end), output_timezone)
+}
+
+pub fn count_weeks_impl<T>(
+    begin: DateTime<T>,
+    end: DateTime<T>,
+    end_precision: Duration,
+    local_timezone: &FixedOffset,
+    extend_begin: bool,
+    extend_end: bool,
+) -> usize
+where
+    T: TimeZone,
+{
+    if begin >= end {
+        return 0;
+    }
+
+    let (first_begin, _) = Week.initial(
+        begin.with_timezone(local_timezone),
+        local_timezone,
+        end_precision,
+        extend_begin,
+    );
+
+    // An interval starting `n` weeks after `first_begin` is enclosed if its
+    // end is before `end`, i.e. `(n + 1) * week < end - first_begin + end_precision`.
+    let span = end.with_timezone(local_timezone) - first_begin + end_precision;
+    let week_secs = Duration::days(7).num_seconds();
+    let span_secs = span.num_seconds();
+    let num_enclosed = if span <= Duration::zero() {
+        0
+    } else if span > Duration::seconds(span_secs) {
+        span_secs / week_secs
+    } else {
+        (span_secs - 1) / week_secs
+    };
+
+    let extended =
+        extend_end && (num_enclosed > 0 || first_begin <= end.with_timezone(local_timezone));
+    num_enclosed as usize + usize::from(extended)
+}
+
+/// Stepper over consecutive intervals in the local timezone.
+///
+/// Intervals are only computed when stepping to them so that no boundary after
+/// the last returned interval is computed.
+struct IntervalStepper {
+    rules: &'static dyn GroupingRules,
+    end_precision: Duration,
+    anchor_mode: AnchorMode,
+    anchor: DateTime<FixedOffset>,
+    index: i64,
+    initial: TimeInterval<FixedOffset>,
+    cur: Option<TimeInterval<FixedOffset>>,
+    /// Year and zero-based month of the current monthly interval on the
+    /// boundaries, so that the next month is not rebuilt from a date.
+    month: Option<(i32, u32)>,
+}
+
+impl IntervalStepper {
+    fn new<T>(
+        begin: DateTime<T>,
+        grouping: &Grouping,
+        end_precision: Duration,
+        local_timezone: &FixedOffset,
+        extend_begin: bool,
+        anchor_mode: &AnchorMode,
+    ) -> Self
+    where
+        T: TimeZone,
+    {
+        let anchor_mode = *anchor_mode;
+        let rules = get_rules(grouping);
+        let begin = begin.with_timezone(local_timezone);
+        let (anchor, index) = match anchor_mode {
+            AnchorMode::Boundary => {
+                let initial = rules.initial(begin, local_timezone, end_precision, extend_begin);
+                let month = matches!(grouping, Grouping::PerMonth).then(|| {
+                    let date = clamped_naive_local(&initial.0).date();
+                    (date.year(), date.month0())
+                });
+                return IntervalStepper {
+                    rules,
+                    end_precision,
+                    anchor_mode,
+                    anchor: initial.0,
+                    index: 0,
+                    initial,
+                    cur: None,
+                    month,
+                };
+            }
+            AnchorMode::Begin => (begin, 0),
+            AnchorMode::At(anchor) => {
+                let anchor = anchor.with_timezone(local_timezone);
+                let index = rules.anchored_index(anchor, begin);
+                match extend_begin {
+                    true => (anchor, index),
+                    false => (anchor, index + 1),
+                }
+            }
+        };
+
+        IntervalStepper {
+            rules,
+            end_precision,
+            anchor_mode,
+            anchor,
+            index,
+            initial: get_anchored_begin_end_times(anchor, rules, index, end_precision),
+            cur: None,
+            month: None,
+        }
+    }
+
+    /// Advance to the next interval and return it.
+    fn step(&mut self) -> TimeInterval<FixedOffset> {
+        let interval = match (self.cur, self.anchor_mode) {
+            (None, _) => self.initial,
+            (Some(cur), AnchorMode::Boundary) => match &mut self.month {
+                Some((year, month0)) => {
+                    (*year, *month0) = match *month0 {
+                        11 => (*year + 1, 0),
+                        _ => (*year, *month0 + 1),
+                    };
+                    get_month_times(*year, *month0, &cur.0.timezone(), self.end_precision)
+                }
+                None => self.rules.next(cur.0, self.end_precision),
+            },
+            (Some(_), AnchorMode::Begin | AnchorMode::At(_)) => get_anchored_begin_end_times(
+                self.anchor,
+                self.rules,
+                self.index,
+                self.end_precision,
+            ),
+        };
+        self.index += 1;
+        self.cur = Some(interval);
+        interval
+    }
+}
+
+/// Stepper over consecutive intervals of a custom grouping strategy.
+struct StrategyStepper<'a> {
+    strategy: &'a dyn GroupingStrategy,
+    end_precision: Duration,
+    initial: TimeInterval<FixedOffset>,
+    cur: Option<TimeInterval<FixedOffset>>,
+}
+
+impl<'a> StrategyStepper<'a> {
+    fn new(
+        begin: DateTime<FixedOffset>,
+        strategy: &'a dyn GroupingStrategy,
+        end_precision: Duration,
+        local_timezone: &FixedOffset,
+        extend_begin: bool,
+    ) -> Self {
+        StrategyStepper {
+            strategy,
+            end_precision,
+            initial: strategy.initial(begin, local_timezone, end_precision, extend_begin),
+            cur: None,
+        }
+    }
+
+    /// Advance to the next interval and return it.
+    fn step(&mut self) -> TimeInterval<FixedOffset> {
+        let interval = match self.cur {
+            None => self.initial,
+            Some(cur) => self.strategy.next(cur.0, self.end_precision),
+        };
+        self.cur = Some(interval);
+        interval
+    }
+}
+
+/// Stepper over the intervals of a built-in grouping or a custom strategy.
+enum Stepper<'a> {
+    Grouping(IntervalStepper),
+    Strategy(StrategyStepper<'a>),
+}
+
+impl Stepper<'_> {
+    fn step(&mut self) -> TimeInterval<FixedOffset> {
+        match self {
+            Stepper::Grouping(stepper) => stepper.step(),
+            Stepper::Strategy(stepper) => stepper.step(),
+        }
+    }
+}
+
+/// Lazy iterator over the intervals between `begin` and `end`.
+///
+/// A single stepper is kept across calls to `next` so that iterating over all
+/// intervals takes linear time, however they are consumed.
+pub struct IntervalIter<'a, U> {
+    stepper: Option<Stepper<'a>>,
+    end: DateTime<FixedOffset>,
+    extend_end: bool,
+    yielded_any: bool,
+    output_timezone: U,
+}
+
+impl<'a, U> IntervalIter<'a, U>
+where
+    U: TimeZone,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<T, V>(
+        begin: DateTime<T>,
+        end: DateTime<V>,
+        grouping: &Grouping,
+        end_precision: Duration,
+        local_timezone: &FixedOffset,
+        output_timezone: &U,
+        extend_begin: bool,
+        extend_end: bool,
+        anchor_mode: &AnchorMode,
+    ) -> Self
+    where
+        T: TimeZone,
+        V: TimeZone,
+    {
+        // `begin` and `end` may be in different timezones
+        let begin = begin.with_timezone(local_timezone);
+        let end = end.with_timezone(local_timezone);
+        let stepper = (begin < end).then(|| {
+            Stepper::Grouping(IntervalStepper::new(
+                begin,
+                grouping,
+                end_precision,
+                local_timezone,
+                extend_begin,
+                anchor_mode,
+            ))
+        });
+        IntervalIter {
+            stepper,
+            end,
+            extend_end,
+            yielded_any: false,
+            output_timezone: output_timezone.clone(),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_strategy<T>(
+        begin: DateTime<T>,
+        end: DateTime<T>,
+        strategy: &'a dyn GroupingStrategy,
+        end_precision: Duration,
+        local_timezone: &FixedOffset,
+        output_timezone: &U,
+        extend_begin: bool,
+        extend_end: bool,
+    ) -> Self
+    where
+        T: TimeZone,
+    {
+        let begin = begin.with_timezone(local_timezone);
+        let end = end.with_timezone(local_timezone);
+        let stepper = (begin < end).then(|| {
+            Stepper::Strategy(StrategyStepper::new(
+                begin,
+                strategy,
+                end_precision,
+                local_timezone,
+                extend_begin,
+            ))
+        });
+        IntervalIter {
+            stepper,
+            end,
+            extend_end,
+            yielded_any: false,
+            output_timezone: output_timezone.clone(),
+        }
+    }
+}
+
+impl<U> Iterator for IntervalIter<'_, U>
+where
+    U: TimeZone,
+{
+    type Item = TimeInterval<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (cur_begin, cur_end) = self.stepper.as_mut()?.step();
+
+        // If `end` is on a boundary, the interval ending `end_precision` before
+        // it is still enclosed, the one starting at `end` is only added if
+        // extended.
+        if cur_end < self.end {
+            self.yielded_any = true;
+            return Some(to_output_timezone(
+                (cur_begin, cur_end),
+                &self.output_timezone,
+            ));
+        }
+
+        // Without extended begin, the first interval can start after `end` and
+        // would not overlap the range at all.
+        self.stepper = None;
+        match self.extend_end && (self.yielded_any || cur_begin <= self.end) {
+            true => Some(to_output_timezone(
+                (cur_begin, cur_end),
+                &self.output_timezone,
+            )),
+            false => None,
+        }
+    }
+}
+
+/// Get the rules of a built-in grouping.
+pub fn get_rules(grouping: &Grouping) -> &'static dyn GroupingRules {
+    match grouping {
+        Grouping::PerQuarterHour => &QuarterHour,
+        Grouping::PerDay => &Day,
+        Grouping::PerWeek => &Week,
+        Grouping::PerTwoWeeks => &TwoWeeks,
+        Grouping::PerMonth => &Month,
+        Grouping::PerYear => &Year,
+        Grouping::PerRetail445 => &Retail445,
+    }
+}
+
+/// Get the interval `index` periods after `anchor`.
+///
+/// Each boundary is computed from `anchor` directly so that e.g. a monthly
+/// interval anchored on the 31st does not drift after a shorter month.
+fn get_anchored_begin_end_times(
+    anchor: DateTime<FixedOffset>,
+    rules: &dyn GroupingRules,
+    index: i64,
+    end_precision: Duration,
+) -> TimeInterval<FixedOffset> {
+    let begin = rules.nth_anchored_begin(anchor, index);
+    (
+        match index < 0 {
+            true => get_previous_begin_time(begin, &anchor.timezone()),
+            false => get_begin_time(begin, &anchor.timezone()),
+        },
+        get_end_time(
+            rules.nth_anchored_begin(anchor, index + 1),
+            &anchor.timezone(),
+            end_precision,
+        ),
+    )
+}
+
+fn to_output_timezone<U>(
+    interval: TimeInterval<FixedOffset>,
+    output_timezone: &U,
+) -> TimeInterval<U>
+where
+    U: TimeZone,
+{
+    (
+        interval.0.with_timezone(output_timezone),
+        interval.1.with_timezone(output_timezone),
+    )
+}
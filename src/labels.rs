@@ -0,0 +1,141 @@
+//! Labels of time intervals.
+//!
+//! Intervals are labeled by the date of their beginning in the local timezone:
+//! - `Grouping::PerQuarterHour`: `YYYY-MM-DDTHH:MM`, e.g. `2022-06-25T08:15`
+//! - `Grouping::PerDay`: `YYYY-MM-DD`, e.g. `2022-06-25`
+//! - `Grouping::PerWeek`: ISO week `YYYY-Www`, e.g. `2022-W25`. The year is
+//!   the ISO week-year, so the week of 2021-01-01 is labeled `2020-W53`. The
+//!   week is numbered with `week_of_year` and `WeekConvention::Iso`.
+//! - `Grouping::PerTwoWeeks`: ISO week of the first week, e.g. `2022-W25`
+//! - `Grouping::PerMonth`: `YYYY-MM`, e.g. `2022-06`
+//! - `Grouping::PerYear`: `YYYY`, e.g. `2022`
+//! - `Grouping::PerRetail445`: `YYYY-MM-DD` of the first day of the period,
+//!   e.g. `2022-01-29`
+use chrono::{
+    DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+};
+
+use crate::{
+    anchor::AnchorMode,
+    intervals_impl::get_n_intervals_impl,
+    offsets::{checked_offset_west, clamped_offset_west},
+    week_of_year, Grouping, IntervalError, TimeInterval, WeekConvention,
+};
+
+/// Time interval with its label.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabeledInterval<T: TimeZone> {
+    /// Label of the interval, see [`get_interval_label`].
+    pub label: String,
+    /// The interval itself.
+    pub interval: TimeInterval<T>,
+    /// Timezone name set with
+    /// [`IntervalGenerator::with_timezone_name`](crate::IntervalGenerator::with_timezone_name).
+    pub timezone_name: Option<String>,
+}
+
+/// Get the label of an interval.
+///
+/// The label is derived from the beginning of the interval in the local
+/// timezone given by `offset_west_seconds`. An offset of a day or more is
+/// clamped to just below a day.
+pub fn get_interval_label<T>(
+    interval: &TimeInterval<T>,
+    grouping: &Grouping,
+    offset_west_seconds: i32,
+) -> String
+where
+    T: TimeZone,
+{
+    let local_timezone = &clamped_offset_west(offset_west_seconds);
+    format_label(&interval.0, grouping, local_timezone)
+}
+
+/// Get the interval in the UTC timezone matching a label.
+///
+/// This is the inverse of [`get_interval_label`]. The label is interpreted in
+/// the local timezone given by `offset_west_seconds` and the interval has the
+/// default `end_precision` of 1ms. An offset of a day or more is rejected
+/// with [`IntervalError::InvalidOffset`].
+pub fn interval_from_label(
+    label: &str,
+    grouping: &Grouping,
+    offset_west_seconds: i32,
+) -> Result<TimeInterval<Utc>, IntervalError> {
+    let invalid_label = || IntervalError::InvalidLabel(label.to_owned());
+    let local_begin = match grouping {
+        Grouping::PerQuarterHour => {
+            let datetime = NaiveDateTime::parse_from_str(label, grouping.label_format())
+                .map_err(|_| invalid_label())?;
+            if datetime.minute() % 15 != 0 {
+                return Err(invalid_label());
+            }
+            datetime
+        }
+        Grouping::PerDay | Grouping::PerRetail445 => {
+            NaiveDate::parse_from_str(label, grouping.label_format())
+                .map_err(|_| invalid_label())?
+                .and_time(NaiveTime::MIN)
+        }
+        // Parse the Monday since the label has no weekday
+        Grouping::PerWeek | Grouping::PerTwoWeeks => {
+            let label_format = format!("{}-%u", grouping.label_format());
+            NaiveDate::parse_from_str(&format!("{}-1", label), &label_format)
+                .map_err(|_| invalid_label())?
+                .and_time(NaiveTime::MIN)
+        }
+        // Parse the first day since the label has no day
+        Grouping::PerMonth => {
+            let label_format = format!("{}-%d", grouping.label_format());
+            NaiveDate::parse_from_str(&format!("{}-01", label), &label_format)
+                .map_err(|_| invalid_label())?
+                .and_time(NaiveTime::MIN)
+        }
+        // Parse the first day since the label has no month and day
+        Grouping::PerYear => {
+            let label_format = format!("{}-%m-%d", grouping.label_format());
+            NaiveDate::parse_from_str(&format!("{}-01-01", label), &label_format)
+                .map_err(|_| invalid_label())?
+                .and_time(NaiveTime::MIN)
+        }
+    };
+
+    let local_timezone = &checked_offset_west(offset_west_seconds)?;
+    let begin = local_timezone
+        .from_local_datetime(&local_begin)
+        .single()
+        .ok_or_else(invalid_label)?;
+    get_n_intervals_impl(
+        begin,
+        1,
+        grouping,
+        Duration::milliseconds(1),
+        local_timezone,
+        &Utc,
+        true,
+        &AnchorMode::Boundary,
+    )
+    .pop()
+    .ok_or_else(invalid_label)
+}
+
+pub(crate) fn format_label<T>(
+    datetime: &DateTime<T>,
+    grouping: &Grouping,
+    local_timezone: &FixedOffset,
+) -> String
+where
+    T: TimeZone,
+{
+    let local_datetime = datetime.with_timezone(local_timezone);
+    match grouping {
+        // Number the week with `week_of_year` so that labels and week numbers
+        // cannot diverge
+        Grouping::PerWeek | Grouping::PerTwoWeeks => format!(
+            "{}-W{:02}",
+            local_datetime.format("%G"),
+            week_of_year(local_datetime.naive_local().date(), WeekConvention::Iso)
+        ),
+        _ => local_datetime.format(grouping.label_format()).to_string(),
+    }
+}
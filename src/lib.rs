@@ -91,6 +91,7 @@
 //!   have to pass 7*3600, so the time difference in seconds with a shift
 //!   towards the west as _positive_ values. Central European Time (CET) at
 //!   GMT+1 for example would need -3600 offset seconds towards the west.
+//!   [`offset_for`] returns the offset for common timezone abbreviations.
 //! - Whether the first interval extends to before `begin` or not: By default,
 //!   the first interval will start on the boundary _before_ `begin`. You can
 //!   switch this off if you want only full intervals that are strickly _after_
@@ -98,6 +99,9 @@
 //! - Whether the last interval extends to _after_ `end` or not: By default, the
 //!   last interval will end at the boundary _after_ `end`. You can switch this
 //!   off if you want only full intervals that are strickly _before_ `end`.
+//! - Where the intervals are anchored: By default, intervals are aligned to the
+//!   boundaries of the grouping. With [`AnchorMode::Begin`], the first interval
+//!   starts exactly at `begin` instead.
 //!
 //! Let's look at an example with all configuration options used:
 //!
@@ -248,18 +252,72 @@
 //! );
 //! ```
 //!
+//! ## Feature flags
+//!
+//! - `time`: Adds the `time_compat` module with functions accepting and
+//!   returning `time::OffsetDateTime` instead of `chrono` types.
+//! - `columnar`: Adds `IntervalGenerator::get_intervals_columnar` returning
+//!   the beginnings and ends as separate columns of Unix timestamps.
+//! - `test-util`: Adds the `test_util` module with assertions on generated
+//!   intervals, e.g. that weeks start on a given weekday.
+//!
+mod anchor;
+mod config;
+mod duration;
+mod error;
+mod fiscal_year;
 mod generator;
 mod grouping;
+mod interval;
 mod intervals;
 mod intervals_impl;
+mod labels;
+mod offsets;
+mod postgres;
+mod precision;
+mod search;
+mod strategy;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+#[cfg(feature = "time")]
+pub mod time_compat;
+mod timestamps;
+mod transform;
+mod week_convention;
+mod wrapper;
 
-use chrono::DateTime;
+pub use anchor::AnchorMode;
+use chrono::{DateTime, Utc};
+pub use config::IntervalConfig;
+pub use error::IntervalError;
+pub use fiscal_year::FiscalYearLabel;
 pub use generator::IntervalGenerator;
 pub use grouping::Grouping;
-pub use intervals::{get_extended_utc_intervals, get_utc_intervals_opts};
+pub use interval::Interval;
+pub use intervals::{
+    count_weeks, count_weeks_opts, get_extended_utc_intervals,
+    get_extended_utc_intervals_with_defaults, get_utc_intervals_opts, quantize, suggest_grouping,
+};
+pub use labels::{get_interval_label, interval_from_label, LabeledInterval};
+pub use offsets::offset_for;
+pub use postgres::interval_to_tstzrange;
+pub use precision::{DynamicPrecision, Micros, Millis, Nanos, Precision};
+pub use search::{cmp_by_start, filter_overlapping, find_interval_containing, normalize};
+pub use strategy::GroupingStrategy;
+pub use timestamps::{interval_from_millis, interval_to_millis};
+pub use transform::{checked_interval, coverage_bitmap, overlap_fraction, pad_intervals};
+pub use week_convention::{week_of_year, WeekConvention, WeekdayNumbering};
+pub use wrapper::Intervals;
 
 /// Error type of the crate.
 pub type Error = Box<dyn std::error::Error>;
 
 /// A tuple of `chrono::DateTime` objects forming a time interval.
 pub type TimeInterval<T> = (DateTime<T>, DateTime<T>);
+
+/// A time interval with its optional predecessor and successor.
+pub type NeighboredInterval = (
+    Option<TimeInterval<Utc>>,
+    TimeInterval<Utc>,
+    Option<TimeInterval<Utc>>,
+);
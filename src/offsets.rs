@@ -0,0 +1,68 @@
+//! Offsets towards the west of common timezone abbreviations.
+//!
+//! All fixed offsets of the crate are constructed here. Chrono only supports
+//! offsets of less than a day, so larger offsets are either rejected with
+//! `IntervalError::InvalidOffset` or clamped to the largest supported offset.
+use chrono::FixedOffset;
+
+use crate::IntervalError;
+
+/// Largest offset in seconds supported by `chrono::FixedOffset`.
+const MAX_OFFSET_SECS: i32 = 86_399;
+
+/// Get the fixed offset `offset_west_seconds` towards the west.
+///
+/// Returns [`IntervalError::InvalidOffset`] if the offset is a day or more.
+pub(crate) fn checked_offset_west(offset_west_seconds: i32) -> Result<FixedOffset, IntervalError> {
+    FixedOffset::west_opt(offset_west_seconds)
+        .ok_or(IntervalError::InvalidOffset(offset_west_seconds))
+}
+
+/// Get the fixed offset `offset_west_seconds` towards the west, clamped to
+/// less than a day in both directions.
+pub(crate) fn clamped_offset_west(offset_west_seconds: i32) -> FixedOffset {
+    checked_offset_west(offset_west_seconds.clamp(-MAX_OFFSET_SECS, MAX_OFFSET_SECS))
+        .expect("clamped offset is in range")
+}
+
+/// Get the `offset_west_seconds` of a common timezone abbreviation.
+///
+/// The offset is the difference to UTC in seconds with a shift towards the
+/// west as positive value, e.g. 7*3600 for Pacific Daylight Time (PDT) at
+/// GMT-7 and -3600 for Central European Time (CET) at GMT+1. The
+/// abbreviation is matched case-insensitively. Abbreviations used for several
+/// timezones are resolved to the most common one, e.g. "IST" is India
+/// Standard Time and "CST" is Central Standard Time in North America.
+///
+/// Returns `None` for unknown abbreviations.
+pub fn offset_for(zone: &str) -> Option<i32> {
+    let offset_east_minutes = match zone.to_ascii_uppercase().as_str() {
+        "UTC" | "GMT" | "WET" => 0,
+        "BST" | "WEST" | "CET" => 60,
+        "CEST" | "EET" | "SAST" => 2 * 60,
+        "EEST" | "MSK" => 3 * 60,
+        "IST" => 5 * 60 + 30,
+        "NPT" => 5 * 60 + 45,
+        "ICT" | "WIB" => 7 * 60,
+        "AWST" | "HKT" | "SGT" => 8 * 60,
+        "JST" | "KST" => 9 * 60,
+        "ACST" => 9 * 60 + 30,
+        "ACDT" => 10 * 60 + 30,
+        "AEST" => 10 * 60,
+        "AEDT" => 11 * 60,
+        "NZST" => 12 * 60,
+        "NZDT" => 13 * 60,
+        "NDT" => -(2 * 60 + 30),
+        "NST" => -(3 * 60 + 30),
+        "ADT" => -3 * 60,
+        "AST" | "EDT" => -4 * 60,
+        "EST" | "CDT" => -5 * 60,
+        "CST" | "MDT" => -6 * 60,
+        "MST" | "PDT" => -7 * 60,
+        "PST" | "AKDT" => -8 * 60,
+        "AKST" => -9 * 60,
+        "HST" => -10 * 60,
+        _ => return None,
+    };
+    Some(-offset_east_minutes * 60)
+}
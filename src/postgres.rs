@@ -0,0 +1,25 @@
+//! Formatting of time intervals as PostgreSQL range literals.
+use chrono::Utc;
+
+use crate::TimeInterval;
+
+/// Format an interval as a PostgreSQL `tstzrange` literal.
+///
+/// The interval is formatted as closed range `["<begin>","<end>"]` or, with
+/// `half_open`, as `["<begin>","<end>")` which excludes the end. For half-open
+/// ranges of consecutive intervals, generate the intervals with an
+/// `end_precision` of zero so that each range ends on the next boundary, e.g.
+/// `["2022-06-25 00:00:00+00","2022-06-26 00:00:00+00")`.
+pub fn interval_to_tstzrange(interval: &TimeInterval<Utc>, half_open: bool) -> String {
+    let format = "%Y-%m-%d %H:%M:%S%.f+00";
+    let closing_bracket = match half_open {
+        true => ')',
+        false => ']',
+    };
+    format!(
+        "[\"{}\",\"{}\"{}",
+        interval.0.format(format),
+        interval.1.format(format),
+        closing_bracket
+    )
+}
@@ -0,0 +1,59 @@
+//! Precision of interval ends known at compile time.
+//!
+//! An [`IntervalGenerator`](crate::IntervalGenerator) stores its precision as
+//! a `Precision`. The default `DynamicPrecision` holds a `chrono::Duration`
+//! set at runtime, while `Millis`, `Micros` and `Nanos` are zero-sized and
+//! bake the precision into the type.
+use chrono::Duration;
+
+/// Time span between the end of one interval and the beginning of the next.
+pub trait Precision: Clone {
+    /// Get the precision as a duration.
+    fn end_precision(&self) -> Duration;
+}
+
+/// Precision set at runtime, 1ms by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynamicPrecision(pub Duration);
+
+impl Default for DynamicPrecision {
+    fn default() -> Self {
+        DynamicPrecision(Duration::milliseconds(1))
+    }
+}
+
+impl Precision for DynamicPrecision {
+    fn end_precision(&self) -> Duration {
+        self.0
+    }
+}
+
+/// Precision of one millisecond.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Millis;
+
+impl Precision for Millis {
+    fn end_precision(&self) -> Duration {
+        Duration::milliseconds(1)
+    }
+}
+
+/// Precision of one microsecond.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Micros;
+
+impl Precision for Micros {
+    fn end_precision(&self) -> Duration {
+        Duration::microseconds(1)
+    }
+}
+
+/// Precision of one nanosecond.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Nanos;
+
+impl Precision for Nanos {
+    fn end_precision(&self) -> Duration {
+        Duration::nanoseconds(1)
+    }
+}
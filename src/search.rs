@@ -0,0 +1,85 @@
+//! Ordering, normalization and lookup of time intervals.
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::TimeInterval;
+
+/// Compare two intervals by their beginning.
+///
+/// This can be passed to e.g. `sort_by` to sort intervals by their start.
+pub fn cmp_by_start<T>(a: &TimeInterval<T>, b: &TimeInterval<T>) -> Ordering
+where
+    T: TimeZone,
+{
+    a.0.cmp(&b.0)
+}
+
+/// Find the index of the interval containing `timestamp`.
+///
+/// The intervals have to be sorted by their beginning and must not overlap.
+/// The lookup is a binary search. Both the beginning and the end of an
+/// interval are contained in it. A timestamp in the gap of `end_precision`
+/// between two intervals is not contained in either of them.
+pub fn find_interval_containing<T>(
+    intervals: &[TimeInterval<T>],
+    timestamp: &DateTime<T>,
+) -> Option<usize>
+where
+    T: TimeZone,
+{
+    let num_started = intervals.partition_point(|interval| interval.0 <= *timestamp);
+    let candidate = num_started.checked_sub(1)?;
+    match *timestamp <= intervals[candidate].1 {
+        true => Some(candidate),
+        false => None,
+    }
+}
+
+/// Sort intervals by their beginning and merge overlapping and adjacent ones.
+///
+/// Intervals are merged if one begins at most `end_precision` after the end
+/// of another, so consecutive generated intervals are merged into one.
+/// Intervals separated by a larger gap are kept apart. The result can be
+/// passed to functions expecting sorted intervals which do not overlap, e.g.
+/// [`find_interval_containing`].
+pub fn normalize<T>(intervals: &[TimeInterval<T>], end_precision: Duration) -> Vec<TimeInterval<T>>
+where
+    T: TimeZone,
+{
+    let mut sorted = intervals.to_vec();
+    sorted.sort_by(cmp_by_start);
+
+    let mut normalized: Vec<TimeInterval<T>> = Vec::with_capacity(sorted.len());
+    for interval in sorted {
+        match normalized.last_mut() {
+            Some(last) if interval.0 <= last.1.clone() + end_precision => {
+                if interval.1 > last.1 {
+                    last.1 = interval.1;
+                }
+            }
+            _ => normalized.push(interval),
+        }
+    }
+    normalized
+}
+
+/// Get the intervals overlapping the `query` range.
+///
+/// An interval overlaps the query if it ends at or after the beginning of the
+/// query and begins at or before its end, so intervals touching the query
+/// only at a boundary are included. Unlike [`find_interval_containing`], the
+/// intervals do not have to be sorted and keep their order.
+pub fn filter_overlapping<T>(
+    intervals: &[TimeInterval<T>],
+    query: &TimeInterval<T>,
+) -> Vec<TimeInterval<T>>
+where
+    T: TimeZone,
+{
+    intervals
+        .iter()
+        .filter(|interval| interval.1 >= query.0 && interval.0 <= query.1)
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,57 @@
+//! Custom groupings of time intervals.
+//!
+//! Implement `GroupingStrategy` to group intervals by other rules than the
+//! built-in groupings, e.g. every 10 hours, and set it with
+//! `IntervalGenerator::with_strategy`. All built-in groupings implement the
+//! trait as well.
+use std::panic::RefUnwindSafe;
+
+use chrono::{DateTime, Duration, FixedOffset};
+
+use crate::{intervals_impl::get_rules, Grouping, TimeInterval};
+
+/// Rule to group time into consecutive intervals.
+///
+/// Strategies are shared between threads and must not break the unwind
+/// safety of [`IntervalGenerator`](crate::IntervalGenerator).
+pub trait GroupingStrategy: Send + Sync + RefUnwindSafe {
+    /// Get the first interval for `begin` in the local timezone.
+    ///
+    /// With `extend_begin`, this is the interval containing `begin`,
+    /// otherwise the first interval starting after `begin`. The interval ends
+    /// `end_precision` before the beginning of the next one.
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset>;
+
+    /// Get the interval after the one starting at `cur_begin`.
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset>;
+}
+
+impl GroupingStrategy for Grouping {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        get_rules(self).initial(begin, local_timezone, end_precision, extend_begin)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        get_rules(self).next(cur_begin, end_precision)
+    }
+}
@@ -0,0 +1,29 @@
+//! Assertions on generated intervals for tests of downstream crates.
+//!
+//! Enable the `test-util` feature to use them.
+use chrono::{Datelike, Utc, Weekday};
+
+use crate::{offsets::clamped_offset_west, TimeInterval};
+
+/// Assert that all intervals start on `weekday` in the local timezone.
+///
+/// The local timezone is the fixed offset `offset_west_seconds` towards the
+/// west, clamped like in [`IntervalGenerator::with_offset_west_secs`](crate::IntervalGenerator::with_offset_west_secs).
+///
+/// # Panics
+///
+/// Panics if an interval does not start on `weekday`.
+pub fn assert_weeks_start_on(
+    intervals: &[TimeInterval<Utc>],
+    weekday: Weekday,
+    offset_west_seconds: i32,
+) {
+    let local_timezone = clamped_offset_west(offset_west_seconds);
+    for interval in intervals {
+        assert_eq!(
+            interval.0.with_timezone(&local_timezone).weekday(),
+            weekday,
+            "interval {interval:?} does not start on {weekday:?}"
+        );
+    }
+}
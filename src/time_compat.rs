@@ -0,0 +1,105 @@
+//! Time intervals for `time::OffsetDateTime` inputs.
+//!
+//! These functions mirror the ones in the crate root but accept and return
+//! [`time::OffsetDateTime`]. The boundary computations are done with `chrono`
+//! internally and the returned date times are in UTC. Intervals with a
+//! bound outside the range of `time::OffsetDateTime` are left out, and inputs
+//! outside the range of `chrono` result in no intervals.
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use time::OffsetDateTime;
+
+use crate::{Grouping, IntervalGenerator, Precision};
+
+/// A tuple of `time::OffsetDateTime` objects forming a time interval.
+pub type OffsetDateTimeInterval = (OffsetDateTime, OffsetDateTime);
+
+/// Get time intervals with options in the UTC timezone.
+///
+/// See [`crate::get_utc_intervals_opts`] for a description of the options.
+#[must_use]
+pub fn get_utc_intervals_opts(
+    begin: OffsetDateTime,
+    end: OffsetDateTime,
+    grouping: &Grouping,
+    offset_west_seconds: i32,
+    end_precision: Duration,
+    extend_begin: bool,
+    extend_end: bool,
+) -> Vec<OffsetDateTimeInterval> {
+    let (Some(begin), Some(end)) = (to_chrono(begin), to_chrono(end)) else {
+        return Vec::with_capacity(0);
+    };
+    to_offset_date_time_intervals(crate::get_utc_intervals_opts(
+        begin,
+        end,
+        grouping,
+        offset_west_seconds,
+        end_precision,
+        extend_begin,
+        extend_end,
+    ))
+}
+
+/// Get extended time intervals with default options in the UTC timezone.
+///
+/// See [`crate::get_extended_utc_intervals`] for a description of the
+/// defaults.
+#[must_use]
+pub fn get_extended_utc_intervals(
+    begin: OffsetDateTime,
+    end: OffsetDateTime,
+    grouping: &Grouping,
+    offset_west_seconds: i32,
+) -> Vec<OffsetDateTimeInterval> {
+    let (Some(begin), Some(end)) = (to_chrono(begin), to_chrono(end)) else {
+        return Vec::with_capacity(0);
+    };
+    to_offset_date_time_intervals(crate::get_extended_utc_intervals(
+        begin,
+        end,
+        grouping,
+        offset_west_seconds,
+    ))
+}
+
+impl<P: Precision> IntervalGenerator<P> {
+    /// Get the intervals between `begin` and `end` as `time::OffsetDateTime`.
+    #[must_use]
+    pub fn get_offset_date_time_intervals(
+        &self,
+        begin: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Vec<OffsetDateTimeInterval> {
+        let (Some(begin), Some(end)) = (to_chrono(begin), to_chrono(end)) else {
+            return Vec::with_capacity(0);
+        };
+        to_offset_date_time_intervals(self.get_intervals(begin, end))
+    }
+}
+
+fn to_chrono(datetime: OffsetDateTime) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(datetime.unix_timestamp(), datetime.nanosecond())
+        .single()
+}
+
+fn to_offset_date_time(datetime: DateTime<Utc>) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp(datetime.timestamp())
+        .ok()?
+        .checked_add(time::Duration::nanoseconds(
+            datetime.timestamp_subsec_nanos() as i64,
+        ))
+}
+
+fn to_offset_date_time_intervals(
+    intervals: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+) -> Vec<OffsetDateTimeInterval> {
+    intervals
+        .into_iter()
+        .filter_map(|interval| {
+            Some((
+                to_offset_date_time(interval.0)?,
+                to_offset_date_time(interval.1)?,
+            ))
+        })
+        .collect()
+}
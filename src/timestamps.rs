@@ -0,0 +1,30 @@
+//! Conversion of time intervals from and to Unix timestamps.
+use chrono::{TimeZone, Utc};
+
+use crate::TimeInterval;
+
+/// Convert an interval to a pair of Unix timestamps in milliseconds.
+///
+/// Anything below millisecond precision is truncated.
+pub fn interval_to_millis<T>(interval: &TimeInterval<T>) -> (i64, i64)
+where
+    T: TimeZone,
+{
+    (interval.0.timestamp_millis(), interval.1.timestamp_millis())
+}
+
+/// Convert a pair of Unix timestamps in milliseconds to an interval in UTC.
+///
+/// This is the inverse of [`interval_to_millis`].
+///
+/// # Panics
+///
+/// Panics if a timestamp is out of the range supported by `chrono`.
+pub fn interval_from_millis(begin_ms: i64, end_ms: i64) -> TimeInterval<Utc> {
+    let from_millis = |ms| {
+        Utc.timestamp_millis_opt(ms)
+            .single()
+            .expect("timestamp out of range")
+    };
+    (from_millis(begin_ms), from_millis(end_ms))
+}
@@ -0,0 +1,117 @@
+//! Transformations and measures of time intervals.
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::{IntervalError, TimeInterval};
+
+/// Get an interval in UTC from `begin` to `end`, failing if it is inverted.
+///
+/// Returns [`IntervalError::ReversedRange`] if `begin` is after `end`. An
+/// interval with `begin` equal to `end` is valid. The functions taking
+/// intervals, e.g. [`overlap_fraction`], assume intervals validated like
+/// this.
+pub fn checked_interval<T>(
+    begin: DateTime<T>,
+    end: DateTime<T>,
+) -> Result<TimeInterval<Utc>, IntervalError>
+where
+    T: TimeZone,
+{
+    match begin <= end {
+        true => Ok((begin.with_timezone(&Utc), end.with_timezone(&Utc))),
+        false => Err(IntervalError::ReversedRange),
+    }
+}
+
+/// Pad every interval by `before` at its beginning and `after` at its end.
+///
+/// This widens e.g. intervals for queries with a look-back and look-ahead.
+/// Padded consecutive intervals overlap as soon as the padding exceeds the
+/// gap of `end_precision` between them, so they cannot be passed to
+/// functions expecting intervals which do not overlap, e.g.
+/// [`crate::find_interval_containing`].
+///
+/// # Panics
+///
+/// Panics if a padded boundary is out of the range supported by `chrono`.
+pub fn pad_intervals<T>(
+    intervals: &[TimeInterval<T>],
+    before: Duration,
+    after: Duration,
+) -> Vec<TimeInterval<T>>
+where
+    T: TimeZone,
+{
+    intervals
+        .iter()
+        .map(|interval| (interval.0.clone() - before, interval.1.clone() + after))
+        .collect()
+}
+
+/// Get the fraction of `interval` which lies within `window`.
+///
+/// The result is in the range from 0.0 to 1.0, e.g. for pro-rating metrics
+/// of intervals partially within a window. Both the interval and the overlap
+/// are measured as end minus beginning, so the gap of `end_precision` after
+/// an interval does not count towards its length and an interval fully within
+/// the window has a fraction of exactly 1.0. An interval of zero length has a
+/// fraction of 1.0 if it lies within the window and 0.0 otherwise.
+pub fn overlap_fraction<T>(interval: &TimeInterval<T>, window: &TimeInterval<T>) -> f64
+where
+    T: TimeZone,
+{
+    let overlap_begin = (&interval.0).max(&window.0).clone();
+    let overlap_end = (&interval.1).min(&window.1).clone();
+    if overlap_end < overlap_begin {
+        return 0.0;
+    }
+
+    let interval_length = interval.1.clone() - interval.0.clone();
+    if interval_length.is_zero() {
+        return 1.0;
+    }
+    as_nanos(overlap_end - overlap_begin) / as_nanos(interval_length)
+}
+
+/// Get for each slot of length `slot` in `range` whether it is covered by
+/// any of the intervals.
+///
+/// The range is divided into consecutive slots starting at its beginning,
+/// the last slot is truncated at the end of the range. A slot is covered if
+/// an interval overlaps it, even if only partially. The intervals do not
+/// have to be sorted.
+///
+/// # Panics
+///
+/// Panics if `slot` is not positive.
+pub fn coverage_bitmap<T>(
+    intervals: &[TimeInterval<T>],
+    range: &TimeInterval<T>,
+    slot: Duration,
+) -> Vec<bool>
+where
+    T: TimeZone,
+{
+    assert!(slot > Duration::zero(), "slot must be positive");
+
+    let mut bitmap = Vec::new();
+    let mut slot_begin = range.0.clone();
+    while slot_begin < range.1 {
+        let slot_end = (slot_begin.clone() + slot).min(range.1.clone());
+        bitmap.push(
+            intervals
+                .iter()
+                .any(|interval| interval.1 >= slot_begin && interval.0 < slot_end),
+        );
+        slot_begin = slot_end;
+    }
+    bitmap
+}
+
+/// Get the length of a duration in nanoseconds, falling back to the
+/// precision of milliseconds for durations too long for nanoseconds.
+fn as_nanos(duration: Duration) -> f64 {
+    match duration.num_nanoseconds() {
+        Some(nanos) => nanos as f64,
+        None => duration.num_milliseconds() as f64 * 1e6,
+    }
+}
@@ -0,0 +1,52 @@
+//! Numbering of weeks within a year.
+//!
+//! With `WeekConvention::Iso`, weeks start on Monday and week 1 is the week
+//! containing January 4th, so the first days of January can belong to the
+//! last week of the previous year. This is the week number in the labels of
+//! weekly intervals. With `WeekConvention::UsSunday`, weeks start on Sunday
+//! and week 1 is the week containing January 1st, so January 1st is always in
+//! week 1.
+//!
+//! Weekdays are numbered with `WeekdayNumbering`. Chrono counts days from
+//! Monday (`Weekday::num_days_from_monday`, also the value of
+//! `weekday as i64`), which is `WeekdayNumbering::MondayZero`.
+use chrono::{Datelike, NaiveDate, Weekday};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeekConvention {
+    Iso,
+    UsSunday,
+}
+
+/// Get the number of the week containing `date` with the `convention`.
+///
+/// ISO weeks are numbered from 1 to 53 within their ISO week-year, US weeks
+/// from 1 to 54 within the calendar year of `date`.
+pub fn week_of_year(date: NaiveDate, convention: WeekConvention) -> u32 {
+    match convention {
+        WeekConvention::Iso => date.iso_week().week(),
+        WeekConvention::UsSunday => {
+            let jan_1 = date.with_ordinal(1).expect("January 1st is a valid day");
+            (date.ordinal0() + jan_1.weekday().num_days_from_sunday()) / 7 + 1
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeekdayNumbering {
+    /// Monday is day 0 and Sunday is day 6, like chrono.
+    #[default]
+    MondayZero,
+    /// Sunday is day 0 and Saturday is day 6.
+    SundayZero,
+}
+
+impl WeekdayNumbering {
+    /// Get the index of `weekday` from 0 to 6.
+    pub fn index_of(&self, weekday: Weekday) -> u32 {
+        match self {
+            WeekdayNumbering::MondayZero => weekday.num_days_from_monday(),
+            WeekdayNumbering::SundayZero => weekday.num_days_from_sunday(),
+        }
+    }
+}
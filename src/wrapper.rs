@@ -0,0 +1,78 @@
+//! Wrapper around a list of time intervals.
+use std::ops::Deref;
+
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::TimeInterval;
+
+/// List of time intervals with convenience methods.
+///
+/// Dereferences to a slice of [`TimeInterval`] so that all slice methods are
+/// available as well.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Intervals<T: TimeZone> {
+    intervals: Vec<TimeInterval<T>>,
+}
+
+impl<T: TimeZone> Intervals<T> {
+    /// Get the sum of the durations of all intervals.
+    pub fn total_duration(&self) -> Duration {
+        self.intervals
+            .iter()
+            .fold(Duration::zero(), |total, interval| {
+                total + (interval.1.clone() - interval.0.clone())
+            })
+    }
+
+    /// Get the number of intervals.
+    pub fn count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Get the beginning of the first interval.
+    pub fn first_start(&self) -> Option<DateTime<T>> {
+        self.intervals.first().map(|interval| interval.0.clone())
+    }
+
+    /// Get the end of the last interval.
+    pub fn last_end(&self) -> Option<DateTime<T>> {
+        self.intervals.last().map(|interval| interval.1.clone())
+    }
+
+    /// Unwrap the underlying vector of intervals.
+    pub fn into_vec(self) -> Vec<TimeInterval<T>> {
+        self.intervals
+    }
+}
+
+impl<T: TimeZone> From<Vec<TimeInterval<T>>> for Intervals<T> {
+    fn from(intervals: Vec<TimeInterval<T>>) -> Self {
+        Intervals { intervals }
+    }
+}
+
+impl<T: TimeZone> Deref for Intervals<T> {
+    type Target = [TimeInterval<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.intervals
+    }
+}
+
+impl<T: TimeZone> IntoIterator for Intervals<T> {
+    type Item = TimeInterval<T>;
+    type IntoIter = std::vec::IntoIter<TimeInterval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a, T: TimeZone> IntoIterator for &'a Intervals<T> {
+    type Item = &'a TimeInterval<T>;
+    type IntoIter = std::slice::Iter<'a, TimeInterval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
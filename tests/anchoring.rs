@@ -0,0 +1,82 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{AnchorMode, Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_begin_anchored_per_day() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let daily_intervals = IntervalGenerator::new()
+        .with_anchor_mode(AnchorMode::Begin)
+        .get_intervals(begin, end);
+    assert_eq!(
+        daily_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 23, 45),
+                Utc.ymd(2022, 6, 26).and_hms_milli(8, 23, 44, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 26).and_hms(8, 23, 45),
+                Utc.ymd(2022, 6, 27).and_hms_milli(8, 23, 44, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 27).and_hms(8, 23, 45),
+                Utc.ymd(2022, 6, 28).and_hms_milli(8, 23, 44, 999),
+            ),
+        ]
+    );
+
+    let non_extended_daily_intervals = IntervalGenerator::new()
+        .with_anchor_mode(AnchorMode::Begin)
+        .without_extension()
+        .get_intervals(begin, end);
+    assert_eq!(non_extended_daily_intervals, daily_intervals[..2]);
+
+    Ok(())
+}
+
+#[test]
+fn test_begin_anchored_per_month_does_not_drift() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-31T12:00:00.000000Z")?;
+
+    let monthly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_anchor_mode(AnchorMode::Begin)
+        .get_n_intervals(begin, 3);
+    assert_eq!(
+        monthly_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 1, 31).and_hms(12, 0, 0),
+                Utc.ymd(2022, 2, 28).and_hms_milli(11, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 2, 28).and_hms(12, 0, 0),
+                Utc.ymd(2022, 3, 31).and_hms_milli(11, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 3, 31).and_hms(12, 0, 0),
+                Utc.ymd(2022, 4, 30).and_hms_milli(11, 59, 59, 999),
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_begin_anchored_paged() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = begin + Duration::weeks(20);
+
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .with_anchor_mode(AnchorMode::Begin);
+    assert_eq!(
+        inter_gen.get_intervals_paged(begin, end, 5, 5),
+        inter_gen.get_intervals(begin, end)[5..10]
+    );
+
+    Ok(())
+}
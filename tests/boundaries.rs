@@ -0,0 +1,138 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{quantize, Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_get_boundaries() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-26T12:23:45.000000Z")?;
+
+    for grouping in [Grouping::PerDay, Grouping::PerWeek, Grouping::PerMonth] {
+        let inter_gen = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_precision(Duration::microseconds(1));
+        let intervals = inter_gen.get_intervals(begin, end);
+        let boundaries = inter_gen.get_boundaries(begin, end);
+
+        assert_eq!(boundaries.len(), intervals.len() + 1);
+        for (interval, edges) in intervals.iter().zip(boundaries.windows(2)) {
+            assert_eq!(interval.0, edges[0]);
+            assert_eq!(interval.1 + Duration::microseconds(1), edges[1]);
+        }
+    }
+
+    // No boundaries without intervals
+    assert!(IntervalGenerator::new()
+        .get_boundaries(end, begin)
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_boundary_set() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let boundary_set = IntervalGenerator::new().boundary_set(begin, end);
+    assert_eq!(boundary_set.len(), 6);
+    assert_eq!(
+        boundary_set
+            .range(Utc.ymd(2022, 6, 26).and_hms(0, 0, 0)..Utc.ymd(2022, 6, 27).and_hms(0, 0, 0))
+            .collect::<Vec<_>>(),
+        vec![
+            &Utc.ymd(2022, 6, 26).and_hms(0, 0, 0),
+            &Utc.ymd(2022, 6, 26).and_hms_milli(23, 59, 59, 999),
+        ]
+    );
+    assert!(boundary_set.contains(&Utc.ymd(2022, 6, 27).and_hms_milli(23, 59, 59, 999)));
+
+    // Without end precision, the end of an interval is the beginning of the
+    // next one
+    let boundary_set = IntervalGenerator::new()
+        .with_precision(Duration::zero())
+        .boundary_set(begin, end);
+    assert_eq!(boundary_set.len(), 4);
+    assert_eq!(
+        boundary_set.into_iter().collect::<Vec<_>>(),
+        IntervalGenerator::new()
+            .with_precision(Duration::zero())
+            .get_boundaries(begin, end)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_quantize() -> Result<(), Error> {
+    for (timestamp, grouping, offset_west_secs, expected) in [
+        // Past noon rounds to the next day
+        (
+            "2022-06-25T12:30:00.000000Z",
+            Grouping::PerDay,
+            0,
+            Utc.ymd(2022, 6, 26).and_hms(0, 0, 0),
+        ),
+        (
+            "2022-06-25T01:00:00.000000Z",
+            Grouping::PerDay,
+            0,
+            Utc.ymd(2022, 6, 25).and_hms(0, 0, 0),
+        ),
+        // Ties round down
+        (
+            "2022-06-25T12:00:00.000000Z",
+            Grouping::PerDay,
+            0,
+            Utc.ymd(2022, 6, 25).and_hms(0, 0, 0),
+        ),
+        // Noon in UTC is 14:00 in UTC+02:00
+        (
+            "2022-06-25T12:00:00.000000Z",
+            Grouping::PerDay,
+            -7200,
+            Utc.ymd(2022, 6, 25).and_hms(22, 0, 0),
+        ),
+        (
+            "2022-06-25T00:00:00.000000Z",
+            Grouping::PerDay,
+            0,
+            Utc.ymd(2022, 6, 25).and_hms(0, 0, 0),
+        ),
+        // The middle of February is earlier than in March
+        (
+            "2022-02-15T00:00:00.000000Z",
+            Grouping::PerMonth,
+            0,
+            Utc.ymd(2022, 2, 1).and_hms(0, 0, 0),
+        ),
+        (
+            "2022-02-15T12:00:00.000000Z",
+            Grouping::PerMonth,
+            0,
+            Utc.ymd(2022, 3, 1).and_hms(0, 0, 0),
+        ),
+        (
+            "2022-03-15T00:00:00.000000Z",
+            Grouping::PerMonth,
+            0,
+            Utc.ymd(2022, 3, 1).and_hms(0, 0, 0),
+        ),
+        (
+            "2022-03-17T00:00:00.000000Z",
+            Grouping::PerMonth,
+            0,
+            Utc.ymd(2022, 4, 1).and_hms(0, 0, 0),
+        ),
+    ] {
+        let timestamp = DateTime::parse_from_rfc3339(timestamp)?;
+        assert_eq!(
+            quantize(timestamp, &grouping, offset_west_secs),
+            expected,
+            "{} {:?}",
+            timestamp,
+            grouping
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::{sync::mpsc, thread};
+
+use chrono::{DateTime, Duration};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_generate_into_sender() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2021-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerQuarterHour);
+
+    let (tx, rx) = mpsc::channel();
+    let consumer = thread::spawn(move || rx.into_iter().collect::<Vec<_>>());
+    inter_gen.generate_into_sender(begin, end, tx);
+
+    let received_intervals = consumer.join().unwrap();
+    assert_eq!(received_intervals.len(), 35238);
+    assert_eq!(received_intervals, inter_gen.get_intervals(begin, end));
+
+    Ok(())
+}
+
+#[test]
+fn test_generate_into_sender_with_post_processing() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-08-12T12:23:45.000000Z")?;
+    let week_gen = IntervalGenerator::new().with_grouping(Grouping::PerWeek);
+
+    for inter_gen in [
+        week_gen.clone().without_extension().with_snap_inputs(),
+        week_gen.clone().with_clamped_ends(),
+        week_gen
+            .clone()
+            .without_extended_end()
+            .with_min_last_bucket(Duration::hours(1)),
+        week_gen.without_extended_begin().with_partial_first_week(),
+    ] {
+        let (tx, rx) = mpsc::channel();
+        let consumer = thread::spawn(move || rx.into_iter().collect::<Vec<_>>());
+        inter_gen.generate_into_sender(begin, end, tx);
+        assert_eq!(
+            consumer.join().unwrap(),
+            inter_gen.get_intervals(begin, end)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_generate_into_sender_stops_on_hang_up() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2021-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    // Returns without panicking once the receiver is gone
+    let (tx, rx) = mpsc::channel();
+    drop(rx);
+    IntervalGenerator::new()
+        .with_grouping(Grouping::PerQuarterHour)
+        .generate_into_sender(begin, end, tx);
+
+    Ok(())
+}
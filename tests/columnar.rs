@@ -0,0 +1,26 @@
+#![cfg(feature = "columnar")]
+use chrono::DateTime;
+use chrono_intervals::{interval_from_millis, Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_columnar_intervals_match_intervals() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-27T09:31:12.000000Z")?;
+
+    for grouping in [Grouping::PerDay, Grouping::PerWeek, Grouping::PerMonth] {
+        let inter_gen = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_offset_west_secs(7 * 3600);
+        let (begins, ends) = inter_gen.get_intervals_columnar(begin, end);
+        assert_eq!(begins.len(), ends.len());
+
+        let reconstructed_intervals: Vec<_> = begins
+            .into_iter()
+            .zip(ends)
+            .map(|(begin_ms, end_ms)| interval_from_millis(begin_ms, end_ms))
+            .collect();
+        assert_eq!(reconstructed_intervals, inter_gen.get_intervals(begin, end));
+    }
+
+    Ok(())
+}
@@ -1,8 +1,8 @@
+#![allow(dead_code)]
 use chrono::{DateTime, TimeZone, Utc};
 use rand::Rng;
 
-pub fn random_time(start_year: i32) -> DateTime<Utc> {
-    let mut rng = rand::thread_rng();
+pub fn random_time(rng: &mut impl Rng, start_year: i32) -> DateTime<Utc> {
     Utc.ymd(
         start_year + rng.gen_range(0..100),
         rng.gen_range(1..=12),
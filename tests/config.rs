@@ -0,0 +1,97 @@
+use chrono::{DateTime, Duration, Utc};
+use chrono_intervals::{Error, Grouping, IntervalConfig, IntervalGenerator};
+
+#[test]
+fn test_generator_from_config() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-10-02T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-10-18T08:23:45.000000Z")?;
+
+    let config = IntervalConfig {
+        grouping: Grouping::PerWeek,
+        offset_west_seconds: -3600,
+        end_precision_nanos: 1_000,
+        extend_begin: false,
+        extend_end: true,
+    };
+    let inter_gen = IntervalGenerator::from(config);
+    let hand_built_inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .with_precision(Duration::microseconds(1))
+        .with_offset_west_secs(-3600)
+        .without_extended_begin();
+    assert_eq!(
+        inter_gen.get_intervals(begin, end),
+        hand_built_inter_gen.get_intervals(begin, end)
+    );
+
+    // The default config matches the default generator
+    let default_inter_gen: IntervalGenerator = IntervalConfig::default().into();
+    assert_eq!(
+        default_inter_gen.get_intervals(begin, end),
+        IntervalGenerator::default().get_intervals(begin, end)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_generator_into_fn() -> Result<(), Error> {
+    let ranges = [
+        (
+            DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?.with_timezone(&Utc),
+            DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?.with_timezone(&Utc),
+        ),
+        (
+            DateTime::parse_from_rfc3339("2022-01-25T08:23:45.000000Z")?.with_timezone(&Utc),
+            DateTime::parse_from_rfc3339("2022-03-27T09:31:12.000000Z")?.with_timezone(&Utc),
+        ),
+    ];
+
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .with_offset_west_secs(-3600);
+    let get_intervals = inter_gen.clone().into_fn();
+    for (begin, end) in ranges {
+        assert_eq!(
+            get_intervals(begin, end),
+            inter_gen.get_intervals(begin, end)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_key() -> Result<(), Error> {
+    let end = DateTime::parse_from_rfc3339("2022-06-30T09:31:12.000000Z")?;
+    let morning = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let evening = DateTime::parse_from_rfc3339("2022-06-25T21:00:00.000000Z")?;
+    let next_day = DateTime::parse_from_rfc3339("2022-06-26T08:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new();
+    // The key is stable across platforms and Rust versions
+    assert_eq!(inter_gen.cache_key(morning, end), 6_043_504_639_659_467_789);
+    assert_eq!(
+        inter_gen.cache_key(morning, end),
+        inter_gen.cache_key(evening, end)
+    );
+    assert_ne!(
+        inter_gen.cache_key(morning, end),
+        inter_gen.cache_key(next_day, end)
+    );
+
+    // The configuration is part of the key
+    for other_gen in [
+        inter_gen.clone().with_grouping(Grouping::PerWeek),
+        inter_gen.clone().with_offset_west_secs(3600),
+        inter_gen.clone().with_precision(Duration::microseconds(1)),
+        inter_gen.clone().without_extended_end(),
+    ] {
+        assert_ne!(
+            inter_gen.cache_key(morning, end),
+            other_gen.cache_key(morning, end)
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Duration};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_dense_intervals_are_contiguous() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-26T12:23:45.000000Z")?;
+
+    for grouping in [Grouping::PerDay, Grouping::PerWeek, Grouping::PerMonth] {
+        let dense_intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_precision(Duration::microseconds(1))
+            .without_extension()
+            .with_partial_first_week()
+            .with_subwindow(Duration::hours(8), Duration::hours(8))
+            .with_dense()
+            .get_intervals(begin, end);
+
+        assert!(dense_intervals.first().unwrap().0 <= begin);
+        assert!(dense_intervals.last().unwrap().1 >= end);
+        for pair in dense_intervals.windows(2) {
+            assert_eq!(pair[0].1 + Duration::microseconds(1), pair[1].0);
+        }
+
+        // Same as the extended intervals
+        assert_eq!(
+            dense_intervals,
+            IntervalGenerator::new()
+                .with_grouping(grouping)
+                .with_precision(Duration::microseconds(1))
+                .get_intervals(begin, end)
+        );
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
-mod common;
-use chrono::{DateTime, Duration};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use chrono_intervals::{
-    get_extended_utc_intervals, get_utc_intervals_opts, Error, Grouping, IntervalGenerator,
+    get_extended_utc_intervals, get_utc_intervals_opts, Error, Grouping, IntervalError,
+    IntervalGenerator,
 };
 
 #[test]
@@ -53,3 +53,158 @@ fn test_get_utc_intervals_zero_sized() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_get_intervals_checked() -> Result<(), Error> {
+    let inter_gen = IntervalGenerator::new();
+
+    // `end` before `begin`
+    let begin = DateTime::parse_from_rfc3339("2022-11-29T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-10-01T08:23:45.000000Z")?;
+    assert_eq!(
+        inter_gen.get_intervals_checked(begin, end),
+        Err(IntervalError::ReversedRange)
+    );
+
+    // `begin` and `end` equal
+    assert_eq!(inter_gen.get_intervals_checked(begin, begin), Ok(vec![]));
+
+    // Valid range
+    assert_eq!(
+        inter_gen.get_intervals_checked(end, begin)?,
+        inter_gen.get_intervals(end, begin)
+    );
+    assert_eq!(inter_gen.get_intervals_checked(end, begin)?.len(), 60);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_utc_intervals_end_on_boundary() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    // Exactly on a quarter-hour, day, week (Monday) and month boundary
+    let end = DateTime::parse_from_rfc3339("2022-08-01T00:00:00.000000Z")?;
+
+    for grouping in [
+        Grouping::PerQuarterHour,
+        Grouping::PerDay,
+        Grouping::PerWeek,
+        Grouping::PerMonth,
+    ] {
+        // The interval ending right before `end` is always included
+        let non_extended_intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .without_extended_end()
+            .get_intervals(begin, end);
+        assert_eq!(
+            non_extended_intervals.last().unwrap().1 + Duration::milliseconds(1),
+            end
+        );
+
+        // The extended end adds the interval starting at `end`
+        let extended_intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .get_intervals(begin, end);
+        assert_eq!(extended_intervals.len(), non_extended_intervals.len() + 1);
+        assert_eq!(extended_intervals.last().unwrap().0, end);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_get_utc_intervals_point_returns_enclosing() -> Result<(), Error> {
+    let begin_end = DateTime::parse_from_rfc3339("2022-11-29T08:23:45.000000Z")?;
+
+    // Empty by default
+    assert_eq!(
+        IntervalGenerator::new().get_intervals(begin_end, begin_end),
+        Vec::with_capacity(0)
+    );
+
+    // Single enclosing interval if enabled, even without extension
+    let expected_interval = (
+        Utc.ymd(2022, 11, 29).and_hms(0, 0, 0),
+        Utc.ymd(2022, 11, 29).and_hms_milli(23, 59, 59, 999),
+    );
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_point_returns_enclosing()
+            .get_intervals(begin_end, begin_end),
+        vec![expected_interval]
+    );
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_point_returns_enclosing()
+            .without_extension()
+            .get_intervals(begin_end, begin_end),
+        vec![expected_interval]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_get_utc_intervals_extended_end_saturates() -> Result<(), Error> {
+    let end = DateTime::<Utc>::MAX_UTC - Duration::hours(1);
+    let begin = end - Duration::days(3);
+
+    let daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    assert_eq!(daily_intervals.len(), 4);
+    assert_eq!(daily_intervals.last().unwrap().1, DateTime::<Utc>::MAX_UTC);
+
+    let monthly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .get_intervals(begin, end);
+    assert_eq!(monthly_intervals.len(), 1);
+    assert_eq!(
+        monthly_intervals.last().unwrap().1,
+        DateTime::<Utc>::MAX_UTC
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_get_utc_intervals_near_max_without_extended_begin() -> Result<(), Error> {
+    let end = DateTime::<Utc>::MAX_UTC - Duration::hours(1);
+    let begin = end - Duration::days(3);
+
+    // The first boundary after `begin` is out of range for these groupings
+    for grouping in [
+        Grouping::PerWeek,
+        Grouping::PerTwoWeeks,
+        Grouping::PerMonth,
+        Grouping::PerYear,
+        Grouping::PerRetail445,
+    ] {
+        let inter_gen = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .without_extended_begin();
+        let intervals = inter_gen.get_intervals(begin, end);
+        assert!(intervals.iter().all(|interval| interval.0 > begin));
+        assert_eq!(intervals, inter_gen.get_intervals_paged(begin, end, 0, 10));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_next_interval_near_max_saturates() {
+    let end = DateTime::<Utc>::MAX_UTC - Duration::minutes(1);
+
+    for grouping in [
+        Grouping::PerQuarterHour,
+        Grouping::PerDay,
+        Grouping::PerWeek,
+        Grouping::PerMonth,
+        Grouping::PerYear,
+        Grouping::PerRetail445,
+    ] {
+        let (next_begin, next_end) = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .next_interval(end);
+        assert!(next_begin > end);
+        assert_eq!(next_end, DateTime::<Utc>::MAX_UTC);
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::DateTime;
+use chrono_intervals::{suggest_grouping, Error, Grouping, IntervalError, IntervalGenerator};
+
+#[test]
+fn test_grouping_ids_are_stable() {
+    assert_eq!(Grouping::PerQuarterHour.id(), "quarter_hour");
+    assert_eq!(Grouping::PerDay.id(), "day");
+    assert_eq!(Grouping::PerWeek.id(), "week");
+    assert_eq!(Grouping::PerTwoWeeks.id(), "two_weeks");
+    assert_eq!(Grouping::PerMonth.id(), "month");
+    assert_eq!(Grouping::PerYear.id(), "year");
+    assert_eq!(Grouping::PerRetail445.id(), "retail_445");
+}
+
+#[test]
+fn test_grouping_label_formats() {
+    assert_eq!(Grouping::PerQuarterHour.label_format(), "%Y-%m-%dT%H:%M");
+    assert_eq!(Grouping::PerDay.label_format(), "%Y-%m-%d");
+    assert_eq!(Grouping::PerWeek.label_format(), "%G-W%V");
+    assert_eq!(Grouping::PerTwoWeeks.label_format(), "%G-W%V");
+    assert_eq!(Grouping::PerMonth.label_format(), "%Y-%m");
+    assert_eq!(Grouping::PerYear.label_format(), "%Y");
+    assert_eq!(Grouping::PerRetail445.label_format(), "%Y-%m-%d");
+}
+
+#[test]
+fn test_grouping_from_id() {
+    let groupings = [
+        Grouping::PerQuarterHour,
+        Grouping::PerDay,
+        Grouping::PerWeek,
+        Grouping::PerTwoWeeks,
+        Grouping::PerMonth,
+        Grouping::PerYear,
+        Grouping::PerRetail445,
+    ];
+    for grouping in groupings {
+        assert_eq!(grouping.id().parse::<Grouping>(), Ok(grouping));
+    }
+    assert_eq!(
+        "bogus".parse::<Grouping>(),
+        Err(IntervalError::InvalidGrouping("bogus".to_owned()))
+    );
+}
+
+#[test]
+fn test_with_grouping_str() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-10-04T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-10-18T08:23:45.000000Z")?;
+
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_grouping_str("week")?
+            .get_intervals(begin, end),
+        IntervalGenerator::new()
+            .with_grouping(Grouping::PerWeek)
+            .get_intervals(begin, end)
+    );
+    assert!(matches!(
+        IntervalGenerator::new().with_grouping_str("bogus"),
+        Err(IntervalError::InvalidGrouping(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_suggest_grouping() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+
+    for (end, target_buckets, expected_grouping) in [
+        ("2022-06-28T08:23:45.000000Z", 3, Grouping::PerDay),
+        ("2032-06-25T08:23:45.000000Z", 10, Grouping::PerYear),
+        ("2022-12-25T08:23:45.000000Z", 6, Grouping::PerMonth),
+        ("2022-12-25T08:23:45.000000Z", 20, Grouping::PerWeek),
+        ("2022-12-25T08:23:45.000000Z", 1000, Grouping::PerDay),
+    ] {
+        let end = DateTime::parse_from_rfc3339(end)?;
+        assert_eq!(
+            suggest_grouping(begin, end, target_buckets),
+            expected_grouping
+        );
+    }
+
+    Ok(())
+}
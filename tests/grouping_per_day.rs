@@ -1,5 +1,10 @@
-use chrono::{DateTime, Datelike, NaiveTime, TimeZone, Utc};
-use chrono_intervals::{Error, Grouping, IntervalGenerator};
+use std::collections::BTreeSet;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Utc,
+};
+use chrono_intervals::{Error, Grouping, IntervalError, IntervalGenerator};
 
 #[test]
 fn test_per_day_regular() -> Result<(), Error> {
@@ -73,3 +78,325 @@ fn test_per_day_over_a_year() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_per_day_with_day_start_time() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000-04:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000-04:00")?;
+
+    // Trading days start at 09:30 in EDT
+    let inter_gen = IntervalGenerator::new()
+        .with_offset_west_secs(4 * 3600)
+        .with_day_start_time(NaiveTime::from_hms(9, 30, 0));
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2022, 6, 24).and_hms(13, 30, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(13, 29, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 25).and_hms(13, 30, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(13, 29, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 26).and_hms(13, 30, 0),
+            Utc.ymd(2022, 6, 27).and_hms_milli(13, 29, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 27).and_hms(13, 30, 0),
+            Utc.ymd(2022, 6, 28).and_hms_milli(13, 29, 59, 999),
+        ),
+    ];
+    assert_eq!(inter_gen.get_intervals(begin, end), expected_intervals);
+    assert_eq!(
+        inter_gen
+            .get_labeled_intervals(begin, end)
+            .into_iter()
+            .map(|labeled_interval| labeled_interval.label)
+            .collect::<Vec<_>>(),
+        vec!["2022-06-24", "2022-06-25", "2022-06-26", "2022-06-27"]
+    );
+    assert_eq!(
+        inter_gen.previous_interval(begin),
+        (
+            Utc.ymd(2022, 6, 23).and_hms(13, 30, 0),
+            Utc.ymd(2022, 6, 24).and_hms_milli(13, 29, 59, 999),
+        )
+    );
+    assert_eq!(
+        inter_gen.next_interval(end),
+        (
+            Utc.ymd(2022, 6, 28).and_hms(13, 30, 0),
+            Utc.ymd(2022, 6, 29).and_hms_milli(13, 29, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+/// US Eastern time with the daylight saving time rules of 2022.
+#[derive(Clone, Copy, Debug)]
+struct Eastern2022;
+
+impl Eastern2022 {
+    fn is_dst(utc: &NaiveDateTime) -> bool {
+        *utc >= NaiveDate::from_ymd(2022, 3, 13).and_hms(7, 0, 0)
+            && *utc < NaiveDate::from_ymd(2022, 11, 6).and_hms(6, 0, 0)
+    }
+}
+
+impl TimeZone for Eastern2022 {
+    type Offset = FixedOffset;
+
+    fn from_offset(_offset: &FixedOffset) -> Self {
+        Eastern2022
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        let offsets: Vec<_> = [FixedOffset::west(5 * 3600), FixedOffset::west(4 * 3600)]
+            .into_iter()
+            .filter(|offset| self.offset_from_utc_datetime(&(*local - *offset)) == *offset)
+            .collect();
+        match offsets[..] {
+            [] => LocalResult::None,
+            [offset] => LocalResult::Single(offset),
+            [standard, daylight] => LocalResult::Ambiguous(daylight, standard),
+            _ => unreachable!(),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match Self::is_dst(utc) {
+            true => FixedOffset::west(4 * 3600),
+            false => FixedOffset::west(5 * 3600),
+        }
+    }
+}
+
+#[test]
+fn test_per_day_wall_clock_days_over_dst() -> Result<(), Error> {
+    // Daylight saving time ends on 2022-11-06 at 02:00 EDT in New York
+    let begin = DateTime::parse_from_rfc3339("2022-11-05T12:00:00.000000-04:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-11-07T12:00:00.000000-05:00")?;
+
+    let inter_gen = IntervalGenerator::new().with_offset_west_secs(4 * 3600);
+    let wall_clock_intervals = inter_gen
+        .clone()
+        .with_wall_clock_days(Eastern2022)
+        .get_intervals(begin, end);
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2022, 11, 5).and_hms(4, 0, 0),
+            Utc.ymd(2022, 11, 6).and_hms_milli(3, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 11, 6).and_hms(4, 0, 0),
+            Utc.ymd(2022, 11, 7).and_hms_milli(4, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 11, 7).and_hms(5, 0, 0),
+            Utc.ymd(2022, 11, 8).and_hms_milli(4, 59, 59, 999),
+        ),
+    ];
+    assert_eq!(wall_clock_intervals, expected_intervals);
+    assert_eq!(
+        wall_clock_intervals[1].1 - wall_clock_intervals[1].0 + Duration::milliseconds(1),
+        Duration::hours(25)
+    );
+
+    // By default, all days span 24 hours in the fixed offset
+    for interval in inter_gen.get_intervals(begin, end) {
+        assert_eq!(
+            interval.1 - interval.0 + Duration::milliseconds(1),
+            Duration::hours(24)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_wall_clock_days_with_offset_conflict() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-11-05T12:00:00.000000-04:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-11-07T12:00:00.000000-05:00")?;
+
+    let inter_gen = IntervalGenerator::new().with_wall_clock_days(Eastern2022);
+    assert_eq!(inter_gen.get_intervals_checked(begin, end)?.len(), 3);
+
+    for inter_gen in [
+        inter_gen.clone().with_offset_west_secs(4 * 3600),
+        IntervalGenerator::new()
+            .with_offset_west_secs(0)
+            .with_wall_clock_days(Eastern2022),
+    ] {
+        assert_eq!(
+            inter_gen.get_intervals_checked(begin, end),
+            Err(IntervalError::ConflictingTimezones)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_per_day_half_period_phase() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000-07:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000-07:00")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_offset_west_secs(7 * 3600)
+        .with_period_phase(0.5);
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2022, 6, 24).and_hms(19, 0, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(18, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 25).and_hms(19, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(18, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 26).and_hms(19, 0, 0),
+            Utc.ymd(2022, 6, 27).and_hms_milli(18, 59, 59, 999),
+        ),
+    ];
+    assert_eq!(inter_gen.get_intervals(begin, end), expected_intervals);
+    assert_eq!(
+        inter_gen.previous_interval(begin),
+        (
+            Utc.ymd(2022, 6, 23).and_hms(19, 0, 0),
+            Utc.ymd(2022, 6, 24).and_hms_milli(18, 59, 59, 999),
+        )
+    );
+
+    // Without phase, the boundaries are at midnight
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_offset_west_secs(7 * 3600)
+            .with_period_phase(0.0)
+            .get_intervals(begin, end),
+        IntervalGenerator::new()
+            .with_offset_west_secs(7 * 3600)
+            .get_intervals(begin, end)
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "period phase must be at least 0.0 and less than 1.0")]
+fn test_per_day_period_phase_out_of_range() {
+    let _ = IntervalGenerator::new().with_period_phase(1.0);
+}
+
+#[test]
+fn test_per_day_east_offset_over_month_ends() -> Result<(), Error> {
+    let offset = FixedOffset::east(7 * 3600);
+    let inter_gen = IntervalGenerator::new().with_offset_west_secs(-7 * 3600);
+
+    for (year, month, last_day) in [(2022, 1, 31), (2022, 2, 28), (2024, 2, 29), (2022, 12, 31)] {
+        // Early morning on the last day of the month is still the previous
+        // day in UTC
+        let begin = offset.ymd(year, month, last_day).and_hms(5, 0, 0);
+        let end = begin + Duration::days(2);
+
+        let daily_intervals = inter_gen.get_intervals(begin, end);
+        assert_eq!(daily_intervals.len(), 3);
+        assert_eq!(
+            daily_intervals[0].0,
+            Utc.ymd(year, month, last_day).and_hms(0, 0, 0) - Duration::hours(7)
+        );
+        let local_days: Vec<_> = daily_intervals
+            .iter()
+            .map(|interval| interval.0.with_timezone(&offset).naive_local().date())
+            .collect();
+        assert_eq!(local_days[0], NaiveDate::from_ymd(year, month, last_day));
+        assert_eq!(local_days[1].day(), 1);
+        assert_eq!(local_days[2].day(), 2);
+        for interval in daily_intervals.iter() {
+            assert_eq!(
+                interval.0.with_timezone(&offset).time(),
+                NaiveTime::from_hms(0, 0, 0)
+            );
+            assert_eq!(
+                interval.1 - interval.0 + Duration::milliseconds(1),
+                Duration::hours(24)
+            );
+        }
+        for pair in daily_intervals.windows(2) {
+            assert_eq!(pair[0].1 + Duration::milliseconds(1), pair[1].0);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_per_day_open_runs() -> Result<(), Error> {
+    // From Monday to Sunday with the Wednesday closed
+    let begin = DateTime::parse_from_rfc3339("2022-06-27T08:23:45.000000-02:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-03T09:31:12.000000-02:00")?;
+    let closed = BTreeSet::from([NaiveDate::from_ymd(2022, 6, 29)]);
+
+    let inter_gen = IntervalGenerator::new().with_offset_west_secs(2 * 3600);
+    assert_eq!(
+        inter_gen.get_open_runs(begin, end, &closed),
+        vec![
+            (
+                Utc.ymd(2022, 6, 27).and_hms(2, 0, 0),
+                Utc.ymd(2022, 6, 29).and_hms_milli(1, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 30).and_hms(2, 0, 0),
+                Utc.ymd(2022, 7, 4).and_hms_milli(1, 59, 59, 999),
+            ),
+        ]
+    );
+
+    // Closed days at the edges and no closures
+    let closed = BTreeSet::from([
+        NaiveDate::from_ymd(2022, 6, 27),
+        NaiveDate::from_ymd(2022, 7, 2),
+        NaiveDate::from_ymd(2022, 7, 3),
+    ]);
+    assert_eq!(
+        inter_gen.get_open_runs(begin, end, &closed),
+        vec![(
+            Utc.ymd(2022, 6, 28).and_hms(2, 0, 0),
+            Utc.ymd(2022, 7, 2).and_hms_milli(1, 59, 59, 999),
+        )]
+    );
+    assert_eq!(
+        inter_gen.get_open_runs(begin, end, &BTreeSet::new()),
+        vec![(
+            Utc.ymd(2022, 6, 27).and_hms(2, 0, 0),
+            Utc.ymd(2022, 7, 4).and_hms_milli(1, 59, 59, 999),
+        )]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wall_clock_days_near_max_saturate() {
+    let end = DateTime::<Utc>::MAX_UTC - Duration::hours(1);
+    let begin = end - Duration::days(2);
+
+    // The day after the last representable date is out of range
+    for inter_gen in [
+        IntervalGenerator::new().with_wall_clock_days(Utc),
+        IntervalGenerator::new()
+            .with_wall_clock_days(Utc)
+            .without_extended_begin(),
+    ] {
+        let intervals = inter_gen.get_intervals(begin, end);
+        assert_eq!(intervals.last().unwrap().1, DateTime::<Utc>::MAX_UTC);
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Months, NaiveTime, TimeZone, Utc};
 use chrono_intervals::{Error, Grouping, IntervalGenerator};
 
 #[test]
@@ -55,3 +55,223 @@ fn test_per_month_over_several_years() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_per_month_end_of_month() -> Result<(), Error> {
+    for (year, month, last_day) in [(2020, 2, 29), (2021, 2, 28), (2022, 4, 30), (2022, 5, 31)] {
+        let begin = Utc.ymd(year, month, 10).and_hms(8, 23, 45);
+        let end = Utc.ymd(year, month, 20).and_hms(8, 23, 45);
+
+        let monthly_intervals = IntervalGenerator::new()
+            .with_grouping(Grouping::PerMonth)
+            .get_intervals(begin, end);
+        assert_eq!(
+            monthly_intervals,
+            vec![(
+                Utc.ymd(year, month, 1).and_hms(0, 0, 0),
+                Utc.ymd(year, month, last_day)
+                    .and_hms_milli(23, 59, 59, 999),
+            )]
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_per_month_with_duration() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-02-10T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-03-20T08:23:45.000000Z")?;
+
+    let monthly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .get_intervals_with_duration(begin, end);
+    assert_eq!(monthly_intervals.len(), 2);
+    let (february, february_duration) = monthly_intervals[0];
+    let (march, march_duration) = monthly_intervals[1];
+    assert_eq!(february_duration, february.1 - february.0);
+    assert_eq!(
+        february_duration,
+        Duration::days(28) - Duration::milliseconds(1)
+    );
+    assert_eq!(
+        march_duration,
+        Duration::days(31) - Duration::milliseconds(1)
+    );
+    assert!(february_duration < march_duration);
+    assert_eq!(march.0.month(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_per_month_equal_length() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_precision(Duration::microseconds(1))
+        .with_equal_length_months();
+    let monthly_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(monthly_intervals.len(), 6);
+    assert_eq!(monthly_intervals.first().unwrap().0, begin);
+    assert!(monthly_intervals.last().unwrap().1 >= end);
+    for interval in monthly_intervals.iter() {
+        assert_eq!(
+            interval.1 - interval.0,
+            Duration::days(28) - Duration::microseconds(1)
+        );
+    }
+    for window in monthly_intervals.windows(2) {
+        assert_eq!(window[1].0 - window[0].1, Duration::microseconds(1));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_per_month_over_240_months() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2000-01-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2019-12-15T09:31:12.000000Z")?;
+
+    for offset_west_seconds in [0, 7 * 3600, -(5 * 3600 + 30 * 60)] {
+        let local_timezone = FixedOffset::west(offset_west_seconds);
+        for (extend_begin, expected_len) in [(true, 240), (false, 239)] {
+            let mut inter_gen = IntervalGenerator::new()
+                .with_grouping(Grouping::PerMonth)
+                .with_offset_west_secs(offset_west_seconds);
+            if !extend_begin {
+                inter_gen = inter_gen.without_extended_begin();
+            }
+            let monthly_intervals = inter_gen.get_intervals(begin, end);
+            assert_eq!(monthly_intervals.len(), expected_len);
+
+            let first_month = match extend_begin {
+                true => local_timezone.ymd(2000, 1, 1).and_hms(0, 0, 0),
+                false => local_timezone.ymd(2000, 2, 1).and_hms(0, 0, 0),
+            };
+            for (n, interval) in monthly_intervals.iter().enumerate() {
+                let month_begin = first_month + Months::new(n as u32);
+                assert_eq!(interval.0, month_begin);
+                assert_eq!(
+                    interval.1,
+                    month_begin + Months::new(1) - Duration::milliseconds(1)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_days_in_each_interval() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-03-15T08:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerMonth);
+    assert_eq!(
+        inter_gen.days_in_each_interval(begin, end),
+        vec![31, 28, 31]
+    );
+
+    let begin = DateTime::parse_from_rfc3339("2024-02-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2024-02-16T08:23:45.000000Z")?;
+    assert_eq!(inter_gen.days_in_each_interval(begin, end), vec![29]);
+    assert_eq!(
+        inter_gen
+            .clone()
+            .with_grouping(Grouping::PerWeek)
+            .days_in_each_interval(begin, end),
+        vec![7]
+    );
+    assert_eq!(
+        inter_gen
+            .with_grouping(Grouping::PerDay)
+            .days_in_each_interval(begin, end),
+        vec![1, 1]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_month_last_interval_over_years() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2002-06-25T08:23:45.000000-07:00")?;
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_offset_west_secs(7 * 3600);
+
+    for end in [
+        "2022-02-14T08:23:45.000000-07:00",
+        "2022-03-01T00:00:00.000000-07:00",
+        "2024-02-29T23:59:59.999999-07:00",
+    ] {
+        let end = DateTime::parse_from_rfc3339(end)?;
+        for inter_gen in [inter_gen.clone(), inter_gen.clone().without_extension()] {
+            assert_eq!(
+                inter_gen.last_interval(begin, end),
+                inter_gen.get_intervals(begin, end).last().copied(),
+                "{}",
+                end
+            );
+        }
+    }
+    assert_eq!(
+        inter_gen.last_interval(
+            begin,
+            DateTime::parse_from_rfc3339("2022-02-14T08:23:45.000000-07:00")?
+        ),
+        Some((
+            Utc.ymd(2022, 2, 1).and_hms(7, 0, 0),
+            Utc.ymd(2022, 3, 1).and_hms_milli(6, 59, 59, 999),
+        ))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_month_sorted_by_duration() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-12-15T08:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerMonth);
+    let months = |intervals: Vec<(DateTime<Utc>, DateTime<Utc>)>| {
+        intervals
+            .into_iter()
+            .map(|interval| interval.0.month())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        months(inter_gen.get_intervals_sorted_by_duration(begin, end, true)),
+        vec![1, 3, 5, 7, 8, 10, 12, 4, 6, 9, 11, 2]
+    );
+    assert_eq!(
+        months(inter_gen.get_intervals_sorted_by_duration(begin, end, false)),
+        vec![2, 4, 6, 9, 11, 1, 3, 5, 7, 8, 10, 12]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_month_steps_across_year_ends() -> Result<(), Error> {
+    // Without extended begin, the first month is already in the next year
+    let begin = DateTime::parse_from_rfc3339("2021-12-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2024-02-15T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .without_extended_begin();
+    let monthly_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(monthly_intervals.len(), 26);
+
+    // Each interval is the one after the previous interval
+    assert_eq!(monthly_intervals[0].0, Utc.ymd(2022, 1, 1).and_hms(0, 0, 0));
+    for pair in monthly_intervals.windows(2) {
+        assert_eq!(inter_gen.next_interval(pair[0].0), pair[1]);
+    }
+
+    Ok(())
+}
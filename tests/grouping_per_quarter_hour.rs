@@ -0,0 +1,90 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_per_quarter_hour_regular() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:05:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-25T08:55:45.000000Z")?;
+
+    let quarter_hour_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerQuarterHour)
+        .get_intervals(begin, end);
+    assert_eq!(
+        quarter_hour_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 0, 0),
+                Utc.ymd(2022, 6, 25).and_hms_milli(8, 14, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 15, 0),
+                Utc.ymd(2022, 6, 25).and_hms_milli(8, 29, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 30, 0),
+                Utc.ymd(2022, 6, 25).and_hms_milli(8, 44, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 45, 0),
+                Utc.ymd(2022, 6, 25).and_hms_milli(8, 59, 59, 999),
+            ),
+        ]
+    );
+
+    let non_extended_quarter_hour_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerQuarterHour)
+        .without_extension()
+        .get_intervals(begin, end);
+    assert_eq!(
+        non_extended_quarter_hour_intervals,
+        quarter_hour_intervals[1..3]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_quarter_hour_over_a_day() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T00:00:00.000000Z")?;
+    let end = begin + Duration::days(1) - Duration::milliseconds(1);
+
+    let quarter_hour_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerQuarterHour)
+        .get_intervals(begin, end);
+    assert_eq!(quarter_hour_intervals.len(), 96);
+    for interval in quarter_hour_intervals {
+        assert_eq!(
+            interval.1 - interval.0,
+            Duration::minutes(15) - Duration::milliseconds(1)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_per_quarter_hour_with_offset() -> Result<(), Error> {
+    // Nepal Time (NPT) is 5h45 ahead of UTC
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T14:07:00.000000+05:45")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-25T14:20:00.000000+05:45")?;
+
+    let quarter_hour_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerQuarterHour)
+        .with_offset_west_secs(-(5 * 3600 + 45 * 60))
+        .get_intervals(begin, end);
+    assert_eq!(
+        quarter_hour_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 15, 0),
+                Utc.ymd(2022, 6, 25).and_hms_milli(8, 29, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 30, 0),
+                Utc.ymd(2022, 6, 25).and_hms_milli(8, 44, 59, 999),
+            ),
+        ]
+    );
+
+    Ok(())
+}
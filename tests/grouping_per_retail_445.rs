@@ -0,0 +1,75 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{interval_from_label, Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_per_retail_445_fiscal_quarter() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-02-01T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-05-03T09:31:12.000000Z")?;
+
+    let retail_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerRetail445)
+        .with_fiscal_year_start(2, 1)
+        .without_extended_end()
+        .get_intervals(begin, end);
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2022, 2, 1).and_hms(0, 0, 0),
+            Utc.ymd(2022, 2, 28).and_hms_milli(23, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 3, 1).and_hms(0, 0, 0),
+            Utc.ymd(2022, 3, 28).and_hms_milli(23, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 3, 29).and_hms(0, 0, 0),
+            Utc.ymd(2022, 5, 2).and_hms_milli(23, 59, 59, 999),
+        ),
+    ];
+    assert_eq!(retail_intervals, expected_intervals);
+
+    let lengths_in_days: Vec<_> = retail_intervals
+        .iter()
+        .map(|interval| (interval.1 - interval.0 + Duration::milliseconds(1)).num_days())
+        .collect();
+    assert_eq!(lengths_in_days, vec![28, 28, 35]);
+
+    Ok(())
+}
+
+#[test]
+fn test_per_retail_445_over_a_year() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2020-01-01T00:00:00.000000+01:00")?;
+    let end = DateTime::parse_from_rfc3339("2020-12-31T12:00:00.000000+01:00")?;
+
+    let retail_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerRetail445)
+        .with_offset_west_secs(-3600)
+        .get_intervals(begin, end);
+    assert_eq!(retail_intervals.len(), 12);
+    assert_eq!(
+        retail_intervals.first().unwrap().0,
+        Utc.ymd(2019, 12, 31).and_hms(23, 0, 0)
+    );
+
+    // The last period of the leap year extends to the start of the next year
+    let lengths_in_days: Vec<_> = retail_intervals
+        .iter()
+        .map(|interval| (interval.1 - interval.0 + Duration::milliseconds(1)).num_days())
+        .collect();
+    assert_eq!(
+        lengths_in_days,
+        vec![28, 28, 35, 28, 28, 35, 28, 28, 35, 28, 28, 37]
+    );
+    assert_eq!(
+        retail_intervals.last().unwrap().1,
+        Utc.ymd(2020, 12, 31).and_hms_milli(22, 59, 59, 999)
+    );
+
+    // The periods are labeled by their first day
+    assert_eq!(
+        interval_from_label("2020-04-01", &Grouping::PerRetail445, -3600)?,
+        retail_intervals[3]
+    );
+
+    Ok(())
+}
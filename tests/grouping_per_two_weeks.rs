@@ -0,0 +1,103 @@
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_per_two_weeks_regular() -> Result<(), Error> {
+    // 2022-07-01 is a Friday
+    let begin = DateTime::parse_from_rfc3339("2022-07-01T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-20T08:23:45.000000Z")?;
+
+    let two_weekly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerTwoWeeks)
+        .get_intervals(begin, end);
+    assert_eq!(
+        two_weekly_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 6, 27).and_hms(0, 0, 0),
+                Utc.ymd(2022, 7, 10).and_hms_milli(23, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 7, 11).and_hms(0, 0, 0),
+                Utc.ymd(2022, 7, 24).and_hms_milli(23, 59, 59, 999),
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_two_weeks_anchored_to_sprint_start() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-07-01T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-27T08:23:45.000000Z")?;
+    // Sprints start every other Monday, e.g. on 2022-06-27 but not 2022-07-04
+    let sprint_start = Utc.ymd(2022, 1, 10).and_hms(0, 0, 0);
+
+    let sprint_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerTwoWeeks)
+        .with_anchor(sprint_start)
+        .get_intervals(begin, end);
+    assert_eq!(
+        sprint_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 6, 27).and_hms(0, 0, 0),
+                Utc.ymd(2022, 7, 10).and_hms_milli(23, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 7, 11).and_hms(0, 0, 0),
+                Utc.ymd(2022, 7, 24).and_hms_milli(23, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 7, 25).and_hms(0, 0, 0),
+                Utc.ymd(2022, 8, 7).and_hms_milli(23, 59, 59, 999),
+            ),
+        ]
+    );
+
+    // An anchor after the range aligns the intervals the same way
+    let later_sprint_start = Utc.ymd(2023, 1, 9).and_hms(0, 0, 0);
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_grouping(Grouping::PerTwoWeeks)
+            .with_anchor(later_sprint_start)
+            .get_intervals(begin, end),
+        sprint_intervals
+    );
+
+    // Without extended begin, the first sprint starts after `begin`
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_grouping(Grouping::PerTwoWeeks)
+            .with_anchor(sprint_start)
+            .without_extended_begin()
+            .get_intervals(begin, end),
+        sprint_intervals[1..]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_month_anchored() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-03-01T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-01T08:23:45.000000Z")?;
+    let anchor = Utc.ymd(2021, 10, 15).and_hms(12, 0, 0);
+
+    let monthly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_anchor(anchor)
+        .get_intervals(begin, end);
+    assert_eq!(monthly_intervals.len(), 4);
+    assert_eq!(
+        monthly_intervals.first().unwrap().0,
+        Utc.ymd(2022, 2, 15).and_hms(12, 0, 0)
+    );
+    for interval in monthly_intervals {
+        assert_eq!(interval.0.day(), 15);
+        assert_eq!(interval.1.day(), 15);
+    }
+
+    Ok(())
+}
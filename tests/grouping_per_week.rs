@@ -1,5 +1,10 @@
-use chrono::{DateTime, Datelike, NaiveTime, TimeZone, Utc};
-use chrono_intervals::{Error, Grouping, IntervalGenerator};
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc, Weekday};
+#[cfg(feature = "test-util")]
+use chrono_intervals::test_util::assert_weeks_start_on;
+use chrono_intervals::{
+    count_weeks, count_weeks_opts, get_interval_label, get_utc_intervals_opts, Error, Grouping,
+    IntervalGenerator,
+};
 
 #[test]
 fn test_per_week_regular() -> Result<(), Error> {
@@ -59,8 +64,9 @@ fn test_per_week_over_a_year() -> Result<(), Error> {
         .with_grouping(Grouping::PerWeek)
         .get_intervals(begin, end);
     assert_eq!(weekly_intervals.len(), 53);
+    #[cfg(feature = "test-util")]
+    assert_weeks_start_on(&weekly_intervals, Weekday::Mon, 0);
     for interval in weekly_intervals.iter() {
-        assert_eq!(interval.0.weekday(), chrono::Weekday::Mon);
         assert_eq!(interval.1.weekday(), chrono::Weekday::Sun);
         assert_eq!(interval.0.time(), NaiveTime::from_hms(0, 0, 0));
         assert_eq!(
@@ -73,3 +79,244 @@ fn test_per_week_over_a_year() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_per_week_starting_on_sunday() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2021-09-09T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-08T08:23:45.000000Z")?;
+
+    for offset_west_seconds in [0, 7 * 3600, -(5 * 3600 + 30 * 60)] {
+        let weekly_intervals = IntervalGenerator::new()
+            .with_grouping(Grouping::PerWeek)
+            .with_week_start(Weekday::Sun)
+            .with_offset_west_secs(offset_west_seconds)
+            .get_intervals(begin, end);
+        assert_eq!(weekly_intervals.len(), 53);
+        #[cfg(feature = "test-util")]
+        assert_weeks_start_on(&weekly_intervals, Weekday::Sun, offset_west_seconds);
+        for interval in weekly_intervals.iter() {
+            assert_eq!(
+                interval.1 - interval.0 + Duration::milliseconds(1),
+                Duration::days(7)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_per_week_partial_first_week() -> Result<(), Error> {
+    // 2022-06-15 is a Wednesday
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-01T08:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .without_extension();
+    let weekly_intervals = inter_gen.get_intervals(begin, end);
+    let partial_weekly_intervals = inter_gen
+        .with_partial_first_week()
+        .get_intervals(begin, end);
+
+    assert_eq!(
+        partial_weekly_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 6, 15).and_hms(8, 23, 45),
+                Utc.ymd(2022, 6, 19).and_hms_milli(23, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 20).and_hms(0, 0, 0),
+                Utc.ymd(2022, 6, 26).and_hms_milli(23, 59, 59, 999),
+            ),
+        ]
+    );
+    assert_eq!(partial_weekly_intervals[1..], weekly_intervals);
+
+    Ok(())
+}
+
+#[test]
+fn test_count_weeks_over_a_year() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2021-09-09T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-08T08:23:45.000000Z")?;
+
+    assert_eq!(count_weeks(begin, end, 0), 53);
+
+    for (extend_begin, extend_end, expected_count) in [
+        (true, true, 53),
+        (true, false, 52),
+        (false, true, 52),
+        (false, false, 51),
+    ] {
+        let weekly_intervals = get_utc_intervals_opts(
+            begin,
+            end,
+            &Grouping::PerWeek,
+            0,
+            Duration::milliseconds(1),
+            extend_begin,
+            extend_end,
+        );
+        assert_eq!(weekly_intervals.len(), expected_count);
+        assert_eq!(
+            count_weeks_opts(
+                begin,
+                end,
+                0,
+                Duration::milliseconds(1),
+                extend_begin,
+                extend_end
+            ),
+            expected_count
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_count_weeks_on_boundaries() -> Result<(), Error> {
+    // Both `begin` and `end` on a Monday midnight in CET
+    let begin = DateTime::parse_from_rfc3339("2021-12-27T00:00:00.000000+01:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-01-24T00:00:00.000000+01:00")?;
+
+    for precision in [Duration::nanoseconds(1), Duration::days(1)] {
+        for extend_begin in [true, false] {
+            for extend_end in [true, false] {
+                assert_eq!(
+                    count_weeks_opts(begin, end, -3600, precision, extend_begin, extend_end),
+                    get_utc_intervals_opts(
+                        begin,
+                        end,
+                        &Grouping::PerWeek,
+                        -3600,
+                        precision,
+                        extend_begin,
+                        extend_end,
+                    )
+                    .len()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_intervals_by_iso_week() {
+    let inter_gen = IntervalGenerator::new().with_offset_west_secs(7 * 3600);
+
+    // 2020 is an ISO week-year with 53 weeks
+    let weekly_intervals = inter_gen.intervals_by_iso_week(2020);
+    assert_eq!(weekly_intervals.len(), 53);
+    assert_eq!(
+        weekly_intervals.first(),
+        Some(&(
+            1,
+            (
+                Utc.ymd(2019, 12, 30).and_hms(7, 0, 0),
+                Utc.ymd(2020, 1, 6).and_hms_milli(6, 59, 59, 999),
+            )
+        ))
+    );
+    assert_eq!(
+        weekly_intervals.last(),
+        Some(&(
+            53,
+            (
+                Utc.ymd(2020, 12, 28).and_hms(7, 0, 0),
+                Utc.ymd(2021, 1, 4).and_hms_milli(6, 59, 59, 999),
+            )
+        ))
+    );
+    for (week, interval) in weekly_intervals.iter() {
+        assert_eq!(
+            get_interval_label(interval, &Grouping::PerWeek, 7 * 3600),
+            format!("2020-W{:02}", week)
+        );
+    }
+
+    assert_eq!(inter_gen.intervals_by_iso_week(2021).len(), 52);
+
+    // No weeks out of the range supported by chrono
+    assert!(inter_gen.intervals_by_iso_week(i32::MAX).is_empty());
+    assert!(inter_gen.intervals_by_iso_week(262_143).is_empty());
+}
+
+#[test]
+fn test_week_numbers_from_kickoff() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-05-20T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-01T08:23:45.000000Z")?;
+    // Wednesday of the week starting on 2022-05-30
+    let kickoff = Utc.ymd(2022, 6, 1).and_hms(9, 0, 0);
+
+    let numbered_weeks = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .week_numbers_from(begin, end, kickoff);
+    let week_begins: Vec<_> = numbered_weeks
+        .iter()
+        .map(|(week, interval)| (*week, interval.0))
+        .collect();
+    assert_eq!(
+        week_begins,
+        vec![
+            (0, Utc.ymd(2022, 5, 30).and_hms(0, 0, 0)),
+            (1, Utc.ymd(2022, 6, 6).and_hms(0, 0, 0)),
+            (2, Utc.ymd(2022, 6, 13).and_hms(0, 0, 0)),
+            (3, Utc.ymd(2022, 6, 20).and_hms(0, 0, 0)),
+            (4, Utc.ymd(2022, 6, 27).and_hms(0, 0, 0)),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_week_offset_moves_timestamp_into_other_week() -> Result<(), Error> {
+    for (timestamp, offset_west_secs, expected_week_begin) in [
+        // Late Sunday in UTC is early Monday in UTC+02:00, so the local week
+        // starts on that Monday
+        (
+            "2022-07-03T23:30:00.000000Z",
+            -7200,
+            Utc.ymd(2022, 7, 3).and_hms(22, 0, 0),
+        ),
+        // Early Monday in UTC is still Sunday in UTC-05:00, so the local week
+        // started on the Monday before
+        (
+            "2022-07-04T02:00:00.000000Z",
+            5 * 3600,
+            Utc.ymd(2022, 6, 27).and_hms(5, 0, 0),
+        ),
+        // Offsets of almost a day in both directions
+        (
+            "2022-07-03T01:00:00.000000Z",
+            -23 * 3600,
+            Utc.ymd(2022, 7, 3).and_hms(1, 0, 0),
+        ),
+        (
+            "2022-07-04T22:00:00.000000Z",
+            23 * 3600,
+            Utc.ymd(2022, 6, 27).and_hms(23, 0, 0),
+        ),
+    ] {
+        let timestamp = DateTime::parse_from_rfc3339(timestamp)?;
+        let weekly_intervals = IntervalGenerator::new()
+            .with_grouping(Grouping::PerWeek)
+            .with_offset_west_secs(offset_west_secs)
+            .get_intervals(timestamp, timestamp + Duration::days(10));
+        assert_eq!(
+            weekly_intervals[0].0, expected_week_begin,
+            "{} with offset {}",
+            timestamp, offset_west_secs
+        );
+        assert!(weekly_intervals[0].0 <= timestamp && timestamp <= weekly_intervals[0].1);
+        #[cfg(feature = "test-util")]
+        assert_weeks_start_on(&weekly_intervals, Weekday::Mon, offset_west_secs);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{interval_from_label, Error, FiscalYearLabel, Grouping, IntervalGenerator};
+
+#[test]
+fn test_per_year_regular() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2021-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-02-01T08:23:45.000000Z")?;
+
+    let yearly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerYear)
+        .get_intervals(begin, end);
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+            Utc.ymd(2021, 12, 31).and_hms_milli(23, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            Utc.ymd(2022, 12, 31).and_hms_milli(23, 59, 59, 999),
+        ),
+    ];
+    assert_eq!(yearly_intervals, expected_intervals);
+    assert_eq!(
+        interval_from_label("2022", &Grouping::PerYear, 0)?,
+        expected_intervals[1]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_year_fiscal_year_start() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-02-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2023-05-15T08:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerYear)
+        .with_fiscal_year_start(4, 1)
+        .without_extended_begin();
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2022, 4, 1).and_hms(0, 0, 0),
+            Utc.ymd(2023, 3, 31).and_hms_milli(23, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2023, 4, 1).and_hms(0, 0, 0),
+            Utc.ymd(2024, 3, 31).and_hms_milli(23, 59, 59, 999),
+        ),
+    ];
+    assert_eq!(inter_gen.get_intervals(begin, end), expected_intervals);
+
+    let labels = |inter_gen: &IntervalGenerator| {
+        inter_gen
+            .get_labeled_intervals(begin, end)
+            .into_iter()
+            .map(|labeled_interval| labeled_interval.label)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(labels(&inter_gen), vec!["FY2022", "FY2023"]);
+    let inter_gen = inter_gen.with_fiscal_year_label(FiscalYearLabel::EndYear);
+    assert_eq!(labels(&inter_gen), vec!["FY2023", "FY2024"]);
+
+    assert_eq!(
+        inter_gen.previous_interval(Utc.ymd(2023, 4, 1).and_hms(0, 0, 0)),
+        expected_intervals[0]
+    );
+    assert_eq!(
+        inter_gen.next_interval(Utc.ymd(2023, 3, 31).and_hms(12, 0, 0)),
+        expected_intervals[1]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_year_fiscal_year_start_with_offset() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-02-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-05-15T08:23:45.000000Z")?;
+
+    // Fiscal years start on April 1st in CET
+    let fiscal_years = IntervalGenerator::new()
+        .with_grouping(Grouping::PerYear)
+        .with_offset_west_secs(-3600)
+        .with_fiscal_year_start(4, 1)
+        .get_intervals(begin, end);
+    assert_eq!(
+        fiscal_years,
+        vec![
+            (
+                Utc.ymd(2021, 3, 31).and_hms(23, 0, 0),
+                Utc.ymd(2022, 3, 31).and_hms_milli(22, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 3, 31).and_hms(23, 0, 0),
+                Utc.ymd(2023, 3, 31).and_hms_milli(22, 59, 59, 999),
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "fiscal year start must be a valid day in every year")]
+fn test_per_year_fiscal_year_start_on_leap_day() {
+    IntervalGenerator::new().with_fiscal_year_start(2, 29);
+}
+
+#[test]
+fn test_per_year_leap_year_length() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2020-06-25T08:23:45.000000-07:00")?;
+    let end = DateTime::parse_from_rfc3339("2021-06-27T09:31:12.000000-07:00")?;
+
+    for precision in [Duration::milliseconds(1), Duration::nanoseconds(1)] {
+        let yearly_intervals = IntervalGenerator::new()
+            .with_grouping(Grouping::PerYear)
+            .with_precision(precision)
+            .with_offset_west_secs(7 * 3600)
+            .get_intervals(begin, end);
+        assert_eq!(yearly_intervals.len(), 2);
+
+        // 2020 is a leap year
+        assert_eq!(yearly_intervals[0].0, Utc.ymd(2020, 1, 1).and_hms(7, 0, 0));
+        assert_eq!(
+            yearly_intervals[0].1 - yearly_intervals[0].0,
+            Duration::days(366) - precision
+        );
+        assert_eq!(yearly_intervals[1].0, Utc.ymd(2021, 1, 1).and_hms(7, 0, 0));
+        assert_eq!(
+            yearly_intervals[1].1 - yearly_intervals[1].0,
+            Duration::days(365) - precision
+        );
+    }
+
+    Ok(())
+}
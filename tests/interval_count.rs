@@ -0,0 +1,25 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{Error, IntervalGenerator};
+
+#[test]
+fn test_get_n_daily_intervals() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+
+    let daily_intervals = IntervalGenerator::new().get_n_intervals(begin, 10);
+    assert_eq!(daily_intervals.len(), 10);
+    assert_eq!(
+        daily_intervals.first().unwrap().0,
+        Utc.ymd(2022, 6, 25).and_hms(0, 0, 0)
+    );
+    assert_eq!(
+        daily_intervals.last().unwrap().1,
+        Utc.ymd(2022, 7, 4).and_hms_milli(23, 59, 59, 999)
+    );
+    for pair in daily_intervals.windows(2) {
+        assert_eq!(pair[0].1 + Duration::milliseconds(1), pair[1].0);
+    }
+
+    assert_eq!(IntervalGenerator::new().get_n_intervals(begin, 0), vec![]);
+
+    Ok(())
+}
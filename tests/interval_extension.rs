@@ -1,5 +1,7 @@
-use chrono::{DateTime, Duration};
-use chrono_intervals::{get_utc_intervals_opts, Error, Grouping, IntervalGenerator};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{
+    count_weeks_opts, get_utc_intervals_opts, Error, Grouping, IntervalGenerator,
+};
 
 #[test]
 fn test_get_utc_intervals_non_extended() -> Result<(), Error> {
@@ -112,3 +114,213 @@ fn test_get_utc_intervals_both_extended() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_coverage_of_sub_day_range() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-25T10:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerQuarterHour);
+    assert_eq!(
+        inter_gen.coverage(begin, end),
+        (
+            Utc.ymd(2022, 6, 25).and_hms(8, 15, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(10, 44, 59, 999),
+        )
+    );
+    assert_eq!(
+        inter_gen.without_extension().coverage(begin, end),
+        (
+            Utc.ymd(2022, 6, 25).and_hms(8, 30, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(10, 29, 59, 999),
+        )
+    );
+
+    // No daily interval fits into the range without extension
+    assert_eq!(
+        IntervalGenerator::new()
+            .without_extension()
+            .coverage(begin, end),
+        (begin.with_timezone(&Utc), begin.with_timezone(&Utc))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_snap_inputs_ignores_extension() -> Result<(), Error> {
+    for (begin, end) in [
+        ("2022-06-15T08:23:45.000000Z", "2022-08-17T09:31:12.000000Z"),
+        ("2022-06-01T00:00:00.000000Z", "2022-09-01T00:00:00.000000Z"),
+    ] {
+        let begin = DateTime::parse_from_rfc3339(begin)?;
+        let end = DateTime::parse_from_rfc3339(end)?;
+
+        for inter_gen in [
+            IntervalGenerator::new(),
+            IntervalGenerator::new().without_extended_begin(),
+            IntervalGenerator::new().without_extended_end(),
+            IntervalGenerator::new().without_extension(),
+        ] {
+            let monthly_intervals = inter_gen
+                .with_grouping(Grouping::PerMonth)
+                .with_snap_inputs()
+                .get_intervals(begin, end);
+            assert_eq!(monthly_intervals.len(), 3);
+            assert_eq!(
+                monthly_intervals.first().unwrap().0,
+                Utc.ymd(2022, 6, 1).and_hms(0, 0, 0)
+            );
+            assert_eq!(
+                monthly_intervals.last().unwrap().1,
+                Utc.ymd(2022, 8, 31).and_hms_milli(23, 59, 59, 999)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_per_week_clamped_ends() -> Result<(), Error> {
+    // From a Wednesday to a Thursday two weeks later
+    let begin = DateTime::parse_from_rfc3339("2022-06-22T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-07T15:00:00.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerWeek);
+    let clamped_intervals = inter_gen
+        .clone()
+        .with_clamped_ends()
+        .get_intervals(begin, end);
+    let full_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(clamped_intervals.len(), 3);
+    assert_eq!(clamped_intervals[..2], full_intervals[..2]);
+    assert_eq!(
+        clamped_intervals[2],
+        (
+            Utc.ymd(2022, 7, 4).and_hms(0, 0, 0),
+            Utc.ymd(2022, 7, 7).and_hms(15, 0, 0),
+        )
+    );
+
+    // An interval starting exactly at `end` is dropped
+    let end = DateTime::parse_from_rfc3339("2022-07-04T00:00:00.000000Z")?;
+    let clamped_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .with_clamped_ends()
+        .get_intervals(begin, end);
+    assert_eq!(clamped_intervals.len(), 2);
+    assert_eq!(
+        clamped_intervals[1],
+        (
+            Utc.ymd(2022, 6, 27).and_hms(0, 0, 0),
+            Utc.ymd(2022, 7, 3).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+
+    // Dense intervals are not clamped
+    let end = DateTime::parse_from_rfc3339("2022-07-07T15:00:00.000000Z")?;
+    let dense_gen = inter_gen.with_dense();
+    assert_eq!(
+        dense_gen
+            .clone()
+            .with_clamped_ends()
+            .get_intervals(begin, end),
+        dense_gen.get_intervals(begin, end)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_min_last_bucket() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T00:00:00.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T02:00:00.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().without_extended_end();
+    let full_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(full_intervals.len(), 2);
+
+    // The remainder of 2 hours is kept with a minimum of 1 hour
+    let intervals = inter_gen
+        .clone()
+        .with_min_last_bucket(Duration::hours(1))
+        .get_intervals(begin, end);
+    assert_eq!(intervals[..2], full_intervals[..]);
+    assert_eq!(
+        intervals[2],
+        (
+            Utc.ymd(2022, 6, 27).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 27).and_hms(2, 0, 0),
+        )
+    );
+
+    // ...but dropped with a minimum of 3 hours
+    assert_eq!(
+        inter_gen
+            .clone()
+            .with_min_last_bucket(Duration::hours(3))
+            .get_intervals(begin, end),
+        full_intervals
+    );
+
+    // No effect with extended end
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_min_last_bucket(Duration::hours(1))
+            .get_intervals(begin, end),
+        IntervalGenerator::new().get_intervals(begin, end)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extended_end_without_extended_begin_in_sub_interval_range() -> Result<(), Error> {
+    // Both are on the same day, so the first boundary after `begin` is after
+    // `end` and the next day would not overlap the range
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T10:00:00.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-25T14:00:00.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().without_extended_begin();
+    assert!(inter_gen.get_intervals(begin, end).is_empty());
+    assert!(inter_gen.get_intervals_paged(begin, end, 0, 10).is_empty());
+    assert_eq!(inter_gen.first_interval(begin, end), None);
+    assert_eq!(inter_gen.last_interval(begin, end), None);
+    assert!(get_utc_intervals_opts(
+        begin,
+        end,
+        &Grouping::PerDay,
+        0,
+        Duration::milliseconds(1),
+        false,
+        true
+    )
+    .is_empty());
+    assert_eq!(
+        count_weeks_opts(begin, end, 0, Duration::milliseconds(1), false, true),
+        0
+    );
+
+    // The day starting exactly at `end` contains it and is still extended to
+    let end = DateTime::parse_from_rfc3339("2022-06-26T00:00:00.000000Z")?;
+    let expected_intervals = vec![(
+        Utc.ymd(2022, 6, 26).and_hms(0, 0, 0),
+        Utc.ymd(2022, 6, 26).and_hms_milli(23, 59, 59, 999),
+    )];
+    assert_eq!(inter_gen.get_intervals(begin, end), expected_intervals);
+    assert_eq!(
+        inter_gen.get_intervals_paged(begin, end, 0, 10),
+        expected_intervals
+    );
+    assert_eq!(
+        inter_gen.last_interval(begin, end),
+        Some(expected_intervals[0])
+    );
+
+    // Once `end` is in the following day, that day is extended to
+    let end = DateTime::parse_from_rfc3339("2022-06-26T05:00:00.000000Z")?;
+    assert_eq!(inter_gen.get_intervals(begin, end), expected_intervals);
+
+    Ok(())
+}
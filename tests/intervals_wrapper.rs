@@ -0,0 +1,88 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{Error, Grouping, Interval, IntervalGenerator, Intervals, TimeInterval};
+
+#[test]
+fn test_intervals_wrapper_helpers() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new();
+    let daily_intervals = inter_gen.get_intervals_wrapped(begin, end);
+
+    assert_eq!(daily_intervals.count(), 3);
+    assert_eq!(
+        daily_intervals.first_start(),
+        Some(Utc.ymd(2022, 6, 25).and_hms(0, 0, 0))
+    );
+    assert_eq!(
+        daily_intervals.last_end(),
+        Some(Utc.ymd(2022, 6, 27).and_hms_milli(23, 59, 59, 999))
+    );
+    assert_eq!(
+        daily_intervals.total_duration(),
+        Duration::days(3) - Duration::milliseconds(3)
+    );
+
+    // Slice and iterator ergonomics
+    assert_eq!(daily_intervals[..], inter_gen.get_intervals(begin, end)[..]);
+    assert_eq!((&daily_intervals).into_iter().count(), 3);
+    assert_eq!(
+        daily_intervals.into_iter().collect::<Vec<_>>(),
+        inter_gen.get_intervals(begin, end)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_intervals_wrapper_empty() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+
+    let empty_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .get_intervals_wrapped(begin, begin);
+
+    assert_eq!(empty_intervals, Intervals::from(vec![]));
+    assert_eq!(empty_intervals.count(), 0);
+    assert_eq!(empty_intervals.first_start(), None);
+    assert_eq!(empty_intervals.last_end(), None);
+    assert_eq!(empty_intervals.total_duration(), Duration::zero());
+
+    Ok(())
+}
+
+#[test]
+fn test_structured_intervals() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new();
+    let structured_intervals = inter_gen.get_intervals_structured(begin, end);
+    assert_eq!(
+        structured_intervals[0],
+        Interval {
+            begin: Utc.ymd(2022, 6, 25).and_hms(0, 0, 0),
+            end: Utc.ymd(2022, 6, 25).and_hms_milli(23, 59, 59, 999),
+        }
+    );
+
+    // Both forms convert into each other without loss
+    let tuple_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(
+        structured_intervals
+            .iter()
+            .cloned()
+            .map(|interval| interval.into())
+            .collect::<Vec<TimeInterval<Utc>>>(),
+        tuple_intervals
+    );
+    assert_eq!(
+        tuple_intervals
+            .into_iter()
+            .map(Interval::from)
+            .collect::<Vec<_>>(),
+        structured_intervals
+    );
+
+    Ok(())
+}
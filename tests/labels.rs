@@ -0,0 +1,285 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc, Weekday};
+use chrono_intervals::{
+    get_interval_label, interval_from_label, week_of_year, Error, Grouping, IntervalError,
+    IntervalGenerator, WeekConvention, WeekdayNumbering,
+};
+
+#[test]
+fn test_interval_from_label() -> Result<(), Error> {
+    assert_eq!(
+        interval_from_label("2022-06", &Grouping::PerMonth, 0)?,
+        (
+            Utc.ymd(2022, 6, 1).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 30).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+    assert_eq!(
+        interval_from_label("2022-W25", &Grouping::PerWeek, 0)?,
+        (
+            Utc.ymd(2022, 6, 20).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+    assert_eq!(
+        interval_from_label("2022-06-25", &Grouping::PerDay, 7 * 3600)?,
+        (
+            Utc.ymd(2022, 6, 25).and_hms(7, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(6, 59, 59, 999),
+        )
+    );
+    assert_eq!(
+        interval_from_label("2022-06-25T08:15", &Grouping::PerQuarterHour, 0)?,
+        (
+            Utc.ymd(2022, 6, 25).and_hms(8, 15, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(8, 29, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_interval_from_invalid_label() {
+    for (label, grouping) in [
+        ("2022-06-25", Grouping::PerMonth),
+        ("2022-06", Grouping::PerDay),
+        ("2022-W54", Grouping::PerWeek),
+        ("2022-06-25T08:20", Grouping::PerQuarterHour),
+    ] {
+        assert_eq!(
+            interval_from_label(label, &grouping, 0),
+            Err(IntervalError::InvalidLabel(label.to_owned()))
+        );
+    }
+}
+
+#[test]
+fn test_label_round_trip() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2021-12-10T12:23:45.000000-07:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-03-26T12:23:45.000000-07:00")?;
+    let pdt_offset_west_seconds = 7 * 3600;
+
+    for grouping in [Grouping::PerDay, Grouping::PerWeek, Grouping::PerMonth] {
+        let labeled_intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_offset_west_secs(pdt_offset_west_seconds)
+            .get_labeled_intervals(begin, end);
+        for labeled_interval in labeled_intervals {
+            let parsed_interval =
+                interval_from_label(&labeled_interval.label, &grouping, pdt_offset_west_seconds)?;
+            assert_eq!(parsed_interval, labeled_interval.interval);
+            assert_eq!(
+                get_interval_label(&parsed_interval, &grouping, pdt_offset_west_seconds),
+                labeled_interval.label
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_labeled_intervals_with_timezone_name() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-10T12:23:45.000000-07:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-12T12:23:45.000000-07:00")?;
+
+    let labeled_intervals = IntervalGenerator::new()
+        .with_offset_west_secs(7 * 3600)
+        .with_timezone_name("America/Los_Angeles")
+        .get_labeled_intervals(begin, end);
+    assert_eq!(labeled_intervals.len(), 3);
+    assert_eq!(labeled_intervals[0].label, "2022-06-10");
+    for labeled_interval in labeled_intervals {
+        assert_eq!(
+            labeled_interval.timezone_name.as_deref(),
+            Some("America/Los_Angeles")
+        );
+    }
+
+    // No name by default
+    let labeled_intervals = IntervalGenerator::new().get_labeled_intervals(begin, end);
+    assert!(labeled_intervals
+        .iter()
+        .all(|labeled_interval| labeled_interval.timezone_name.is_none()));
+
+    Ok(())
+}
+
+#[test]
+fn test_week_labels_use_iso_week_year() -> Result<(), Error> {
+    // 2021-01-01 is a Friday in the last ISO week of 2020
+    let begin = DateTime::parse_from_rfc3339("2021-01-01T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2021-01-05T08:23:45.000000Z")?;
+
+    let labels = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .get_labeled_intervals(begin, end)
+        .into_iter()
+        .map(|labeled_interval| labeled_interval.label)
+        .collect::<Vec<_>>();
+    assert_eq!(labels, vec!["2020-W53", "2021-W01"]);
+    assert_eq!(
+        interval_from_label("2020-W53", &Grouping::PerWeek, 0)?.0,
+        Utc.ymd(2020, 12, 28).and_hms(0, 0, 0)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_week_of_year_iso_and_us_conventions() -> Result<(), Error> {
+    for (date, iso_week, us_week) in [
+        // Friday in the last ISO week of 2020 and the first US week
+        (NaiveDate::from_ymd(2021, 1, 1), 53, 1),
+        // A Sunday starts the second US week but not a new ISO week
+        (NaiveDate::from_ymd(2021, 1, 3), 53, 2),
+        (NaiveDate::from_ymd(2021, 1, 4), 1, 2),
+        (NaiveDate::from_ymd(2022, 1, 1), 52, 1),
+        (NaiveDate::from_ymd(2022, 1, 2), 52, 2),
+        (NaiveDate::from_ymd(2023, 1, 1), 52, 1),
+        (NaiveDate::from_ymd(2023, 1, 2), 1, 1),
+        (NaiveDate::from_ymd(2020, 12, 31), 53, 53),
+    ] {
+        assert_eq!(
+            week_of_year(date, WeekConvention::Iso),
+            iso_week,
+            "{}",
+            date
+        );
+        assert_eq!(
+            week_of_year(date, WeekConvention::UsSunday),
+            us_week,
+            "{}",
+            date
+        );
+    }
+
+    // Week labels are numbered with the ISO convention
+    let begin = DateTime::parse_from_rfc3339("2020-12-01T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2021-02-01T08:23:45.000000Z")?;
+    for labeled_interval in IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .get_labeled_intervals(begin, end)
+    {
+        let iso_week = week_of_year(
+            labeled_interval.interval.0.naive_utc().date(),
+            WeekConvention::Iso,
+        );
+        assert!(labeled_interval
+            .label
+            .ends_with(&format!("-W{:02}", iso_week)));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ordinal_labeled_intervals() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new();
+    let ordinal_labeled_intervals = inter_gen.get_ordinal_labeled_intervals(begin, end);
+    assert_eq!(
+        ordinal_labeled_intervals
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Day 1", "Day 2", "Day 3"]
+    );
+    assert_eq!(
+        ordinal_labeled_intervals
+            .into_iter()
+            .map(|(_, interval)| interval)
+            .collect::<Vec<_>>(),
+        inter_gen.get_intervals(begin, end)
+    );
+
+    let weekly_labels: Vec<_> = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .get_ordinal_labeled_intervals(begin, end)
+        .into_iter()
+        .map(|(label, _)| label)
+        .collect();
+    assert_eq!(weekly_labels, vec!["Week 1", "Week 2"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_weekday_numbering() -> Result<(), Error> {
+    assert_eq!(WeekdayNumbering::MondayZero.index_of(Weekday::Mon), 0);
+    assert_eq!(WeekdayNumbering::MondayZero.index_of(Weekday::Sun), 6);
+    assert_eq!(WeekdayNumbering::SundayZero.index_of(Weekday::Sun), 0);
+    assert_eq!(WeekdayNumbering::SundayZero.index_of(Weekday::Sat), 6);
+
+    // Sunday 2022-06-26 at 23:30 in UTC is Monday in UTC+01:00
+    let sunday = DateTime::parse_from_rfc3339("2022-06-26T23:30:00.000000Z")?;
+    let inter_gen = IntervalGenerator::new();
+    assert_eq!(inter_gen.weekday_index(sunday), 6);
+    assert_eq!(
+        inter_gen
+            .clone()
+            .with_weekday_numbering(WeekdayNumbering::SundayZero)
+            .weekday_index(sunday),
+        0
+    );
+    assert_eq!(
+        inter_gen
+            .clone()
+            .with_offset_west_secs(-3600)
+            .weekday_index(sunday),
+        0
+    );
+
+    // Sunday-based indices are shifted by one day throughout the week
+    for n in 0..7 {
+        let timestamp = sunday + Duration::days(n);
+        assert_eq!(
+            inter_gen
+                .clone()
+                .with_weekday_numbering(WeekdayNumbering::SundayZero)
+                .weekday_index(timestamp),
+            (inter_gen.weekday_index(timestamp) + 1) % 7
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_labels_at_end() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-29T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-05T09:31:12.000000Z")?;
+
+    // The week from June 27th to July 3rd is labeled by its end in July
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerWeek);
+    let labels = |inter_gen: &IntervalGenerator| {
+        inter_gen
+            .get_labeled_intervals(begin, end)
+            .into_iter()
+            .map(|labeled_interval| labeled_interval.label)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(labels(&inter_gen), vec!["2022-W26", "2022-W27"]);
+    assert_eq!(
+        labels(&inter_gen.clone().with_label_at_end()),
+        vec!["2022-07-03", "2022-07-10"]
+    );
+
+    // The end date is taken in the local timezone
+    assert_eq!(
+        labels(&inter_gen.with_offset_west_secs(-7200).with_label_at_end()),
+        vec!["2022-07-03", "2022-07-10"]
+    );
+    assert_eq!(
+        labels(
+            &IntervalGenerator::new()
+                .with_grouping(Grouping::PerMonth)
+                .with_label_at_end()
+        ),
+        vec!["2022-06-30", "2022-07-31"]
+    );
+
+    Ok(())
+}
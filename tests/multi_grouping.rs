@@ -0,0 +1,58 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone, Utc, Weekday};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_get_intervals_multi() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-26T12:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_offset_west_secs(-7200)
+        .with_precision(Duration::seconds(1));
+    let groupings = [Grouping::PerDay, Grouping::PerWeek, Grouping::PerMonth];
+    let intervals = inter_gen.get_intervals_multi(begin, end, &groupings);
+
+    assert_eq!(intervals.len(), groupings.len());
+    for grouping in groupings {
+        assert_eq!(
+            intervals[&grouping],
+            IntervalGenerator::new()
+                .with_grouping(grouping)
+                .with_offset_west_secs(-7200)
+                .with_precision(Duration::seconds(1))
+                .get_intervals(begin, end)
+        );
+    }
+    assert_eq!(intervals[&Grouping::PerMonth].len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_group_by_weekday() -> Result<(), Error> {
+    // Two weeks from Monday to Sunday in CEST
+    let begin = DateTime::parse_from_rfc3339("2022-06-06T08:23:45.000000+02:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-19T08:23:45.000000+02:00")?;
+
+    let intervals_per_weekday = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_offset_west_secs(-7200)
+        .group_by_weekday(begin, end);
+
+    assert_eq!(intervals_per_weekday.len(), 7);
+    for (weekday, intervals) in intervals_per_weekday.iter() {
+        assert_eq!(intervals.len(), 2);
+        for interval in intervals {
+            assert_eq!(
+                interval.0.with_timezone(&FixedOffset::east(7200)).weekday(),
+                *weekday
+            );
+        }
+    }
+    assert_eq!(
+        intervals_per_weekday[&Weekday::Mon][1].0,
+        Utc.ymd(2022, 6, 12).and_hms(22, 0, 0)
+    );
+
+    Ok(())
+}
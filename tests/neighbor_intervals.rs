@@ -0,0 +1,224 @@
+use chrono::{DateTime, Duration, Months, TimeZone, Utc};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_previous_interval_per_month_over_new_year() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-03T08:23:45.000000Z")?;
+
+    let previous_interval = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .previous_interval(begin);
+    assert_eq!(
+        previous_interval,
+        (
+            Utc.ymd(2021, 12, 1).and_hms(0, 0, 0),
+            Utc.ymd(2021, 12, 31).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_previous_interval_per_month_with_offset() -> Result<(), Error> {
+    // In PDT, this is still in February
+    let begin = DateTime::parse_from_rfc3339("2022-03-01T03:00:00.000000Z")?;
+
+    let previous_interval = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_offset_west_secs(7 * 3600)
+        .previous_interval(begin);
+    assert_eq!(
+        previous_interval,
+        (
+            Utc.ymd(2022, 1, 1).and_hms(7, 0, 0),
+            Utc.ymd(2022, 2, 1).and_hms_milli(6, 59, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_previous_interval_per_day_and_week() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-03T08:23:45.000000Z")?;
+
+    assert_eq!(
+        IntervalGenerator::new().previous_interval(begin),
+        (
+            Utc.ymd(2022, 1, 2).and_hms(0, 0, 0),
+            Utc.ymd(2022, 1, 2).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_grouping(Grouping::PerWeek)
+            .previous_interval(begin),
+        (
+            Utc.ymd(2021, 12, 27).and_hms(0, 0, 0),
+            Utc.ymd(2022, 1, 2).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_next_interval_per_month_over_new_year() -> Result<(), Error> {
+    let end = DateTime::parse_from_rfc3339("2022-12-28T08:23:45.000000Z")?;
+
+    let next_interval = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .next_interval(end);
+    assert_eq!(
+        next_interval,
+        (
+            Utc.ymd(2023, 1, 1).and_hms(0, 0, 0),
+            Utc.ymd(2023, 1, 31).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_next_interval_per_day_and_week() -> Result<(), Error> {
+    let end = DateTime::parse_from_rfc3339("2022-12-28T08:23:45.000000Z")?;
+
+    assert_eq!(
+        IntervalGenerator::new().next_interval(end),
+        (
+            Utc.ymd(2022, 12, 29).and_hms(0, 0, 0),
+            Utc.ymd(2022, 12, 29).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_grouping(Grouping::PerWeek)
+            .next_interval(end),
+        (
+            Utc.ymd(2023, 1, 2).and_hms(0, 0, 0),
+            Utc.ymd(2023, 1, 8).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_latest_complete_interval() {
+    let now = Utc.ymd(2022, 6, 15).and_hms(8, 23, 45);
+
+    assert_eq!(
+        IntervalGenerator::new()
+            .with_grouping(Grouping::PerMonth)
+            .latest_complete_interval(now),
+        (
+            Utc.ymd(2022, 5, 1).and_hms(0, 0, 0),
+            Utc.ymd(2022, 5, 31).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+    assert_eq!(
+        IntervalGenerator::new().latest_complete_interval(now),
+        (
+            Utc.ymd(2022, 6, 14).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 14).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+
+    // On a boundary, the interval ending right before is complete
+    let midnight = Utc.ymd(2022, 6, 15).and_hms(0, 0, 0);
+    assert_eq!(
+        IntervalGenerator::new()
+            .latest_complete_interval(midnight)
+            .0,
+        Utc.ymd(2022, 6, 14).and_hms(0, 0, 0),
+    );
+}
+
+#[test]
+fn test_trailing_periods() {
+    let now = Utc.ymd(2022, 6, 15).and_hms(8, 23, 45);
+
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerMonth);
+    let monthly_intervals = inter_gen.trailing_periods(now, 12);
+    let expected_intervals: Vec<_> = (0..12)
+        .map(|n| {
+            let month_begin = Utc.ymd(2021, 6, 1).and_hms(0, 0, 0) + Months::new(n);
+            (
+                month_begin,
+                month_begin + Months::new(1) - Duration::milliseconds(1),
+            )
+        })
+        .collect();
+    assert_eq!(monthly_intervals, expected_intervals);
+    assert_eq!(
+        monthly_intervals.last(),
+        Some(&inter_gen.latest_complete_interval(now))
+    );
+
+    assert!(inter_gen.trailing_periods(now, 0).is_empty());
+}
+
+#[test]
+fn test_get_intervals_with_neighbors() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new();
+    let daily_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(daily_intervals.len(), 3);
+    assert_eq!(
+        inter_gen.get_intervals_with_neighbors(begin, end),
+        vec![
+            (None, daily_intervals[0], Some(daily_intervals[1])),
+            (
+                Some(daily_intervals[0]),
+                daily_intervals[1],
+                Some(daily_intervals[2])
+            ),
+            (Some(daily_intervals[1]), daily_intervals[2], None),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_duration_until_next_boundary() -> Result<(), Error> {
+    let now = Utc.ymd(2022, 2, 14).and_hms(18, 30, 0);
+
+    let inter_gen = IntervalGenerator::new();
+    assert_eq!(
+        inter_gen.duration_until_next_boundary(now),
+        Duration::hours(5) + Duration::minutes(30)
+    );
+    assert_eq!(
+        inter_gen
+            .clone()
+            .with_offset_west_secs(-7200)
+            .duration_until_next_boundary(now),
+        Duration::hours(3) + Duration::minutes(30)
+    );
+    assert_eq!(
+        inter_gen.duration_until_next_boundary(Utc.ymd(2022, 2, 15).and_hms(0, 0, 0)),
+        Duration::days(1)
+    );
+
+    // From the middle of February to March 1st
+    let inter_gen = inter_gen.with_grouping(Grouping::PerMonth);
+    assert_eq!(
+        inter_gen.duration_until_next_boundary(now),
+        Duration::days(14) + Duration::hours(5) + Duration::minutes(30)
+    );
+    assert_eq!(
+        inter_gen.duration_until_next_boundary(Utc.ymd(2022, 1, 31).and_hms(23, 0, 0)),
+        Duration::hours(1)
+    );
+    assert_eq!(
+        inter_gen.duration_until_next_boundary(Utc.ymd(2022, 3, 1).and_hms(0, 0, 0)),
+        Duration::days(31)
+    );
+
+    Ok(())
+}
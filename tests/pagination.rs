@@ -0,0 +1,172 @@
+use chrono::{DateTime, Duration};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_get_intervals_paged() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = begin + Duration::days(100);
+
+    let inter_gen = IntervalGenerator::new();
+    let all_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(all_intervals.len(), 101);
+
+    assert_eq!(
+        inter_gen.get_intervals_paged(begin, end, 30, 10),
+        all_intervals[30..40]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_get_intervals_paged_at_the_end() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-10-02T08:23:45.000000Z")?;
+
+    for inter_gen in [
+        IntervalGenerator::new().with_grouping(Grouping::PerWeek),
+        IntervalGenerator::new()
+            .with_grouping(Grouping::PerWeek)
+            .without_extension(),
+    ] {
+        let all_intervals = inter_gen.get_intervals(begin, end);
+        let num_intervals = all_intervals.len();
+
+        assert_eq!(
+            inter_gen.get_intervals_paged(begin, end, num_intervals - 3, 10),
+            all_intervals[num_intervals - 3..]
+        );
+        assert_eq!(
+            inter_gen.get_intervals_paged(begin, end, num_intervals, 10),
+            vec![]
+        );
+        assert_eq!(
+            inter_gen.get_intervals_paged(begin, end, 0, usize::MAX),
+            all_intervals
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_get_intervals_chunked() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-26T12:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new();
+    let intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(intervals.len(), 104);
+
+    for chunk_size in [1, 7, 50, 104, 200] {
+        let chunks = inter_gen
+            .get_intervals_chunked(begin, end, chunk_size)
+            .collect::<Vec<_>>();
+        assert_eq!(chunks.len(), intervals.len().div_ceil(chunk_size));
+        assert!(chunks.iter().all(|chunk| chunk.len() <= chunk_size));
+        assert_eq!(chunks.concat(), intervals);
+    }
+
+    // No chunks for an empty range
+    assert_eq!(inter_gen.get_intervals_chunked(end, begin, 10).count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_intervals_paged_with_snap_inputs() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-08-12T12:23:45.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .without_extension()
+        .with_snap_inputs();
+    let intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(intervals.len(), 9);
+
+    assert_eq!(
+        inter_gen.get_intervals_paged(begin, end, 0, usize::MAX),
+        intervals
+    );
+    assert_eq!(
+        inter_gen.get_intervals_paged(begin, end, 7, 10),
+        intervals[7..]
+    );
+    for chunk_size in [1, 4, 9] {
+        let chunks = inter_gen
+            .get_intervals_chunked(begin, end, chunk_size)
+            .collect::<Vec<_>>();
+        assert_eq!(chunks.concat(), intervals);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_get_intervals_chunked_with_clamped_ends() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-22T08:23:45.000000Z")?;
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .with_clamped_ends();
+
+    // `end` on a Thursday and exactly on a week start
+    for end in ["2022-07-07T15:00:00.000000Z", "2022-07-04T00:00:00.000000Z"] {
+        let end = DateTime::parse_from_rfc3339(end)?;
+        let intervals = inter_gen.get_intervals(begin, end);
+        assert_eq!(intervals.last().map(|last| last.1 <= end), Some(true));
+
+        assert_eq!(
+            inter_gen.get_intervals_paged(begin, end, 0, usize::MAX),
+            intervals
+        );
+        for chunk_size in [1, 2, 3] {
+            let chunks = inter_gen
+                .get_intervals_chunked(begin, end, chunk_size)
+                .collect::<Vec<_>>();
+            assert_eq!(chunks.concat(), intervals);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_get_intervals_chunked_with_min_last_bucket() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T00:00:00.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-07T02:00:00.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new()
+        .without_extended_end()
+        .with_min_last_bucket(Duration::hours(1));
+    let intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(intervals.len(), 13);
+
+    assert_eq!(
+        inter_gen.get_intervals_paged(begin, end, 12, 10),
+        intervals[12..]
+    );
+    for chunk_size in [1, 5, 12, 13] {
+        let chunks = inter_gen
+            .get_intervals_chunked(begin, end, chunk_size)
+            .collect::<Vec<_>>();
+        assert_eq!(chunks.concat(), intervals);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_get_intervals_chunked_is_linear() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2020-01-01T00:00:00.000000Z")?;
+    let end = begin + Duration::days(3 * 365);
+
+    // Quadratic chunking would step over ~5 billion intervals here
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerQuarterHour);
+    assert_eq!(
+        inter_gen.get_intervals_chunked(begin, end, 1).count(),
+        3 * 365 * 96 + 1
+    );
+
+    Ok(())
+}
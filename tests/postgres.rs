@@ -0,0 +1,44 @@
+use chrono::{DateTime, Duration};
+use chrono_intervals::{interval_to_tstzrange, Error, IntervalGenerator};
+
+#[test]
+fn test_interval_to_tstzrange_closed() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-26T09:31:12.000000Z")?;
+
+    let daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    assert_eq!(
+        daily_intervals
+            .iter()
+            .map(|interval| interval_to_tstzrange(interval, false))
+            .collect::<Vec<_>>(),
+        vec![
+            "[\"2022-06-25 00:00:00+00\",\"2022-06-25 23:59:59.999+00\"]",
+            "[\"2022-06-26 00:00:00+00\",\"2022-06-26 23:59:59.999+00\"]",
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_interval_to_tstzrange_half_open() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-26T09:31:12.000000Z")?;
+
+    let daily_intervals = IntervalGenerator::new()
+        .with_precision(Duration::zero())
+        .get_intervals(begin, end);
+    assert_eq!(
+        daily_intervals
+            .iter()
+            .map(|interval| interval_to_tstzrange(interval, true))
+            .collect::<Vec<_>>(),
+        vec![
+            "[\"2022-06-25 00:00:00+00\",\"2022-06-26 00:00:00+00\")",
+            "[\"2022-06-26 00:00:00+00\",\"2022-06-27 00:00:00+00\")",
+        ]
+    );
+
+    Ok(())
+}
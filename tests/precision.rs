@@ -1,5 +1,8 @@
-use chrono::{DateTime, Duration, NaiveTime};
-use chrono_intervals::{get_utc_intervals_opts, Error, Grouping, IntervalGenerator};
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Utc};
+use chrono_intervals::{
+    get_utc_intervals_opts, Error, Grouping, IntervalError, IntervalGenerator, Micros, Millis,
+    Nanos, Precision,
+};
 
 #[test]
 fn test_get_utc_intervals_precision_millis() -> Result<(), Error> {
@@ -103,3 +106,232 @@ fn test_get_utc_intervals_precision_nanos() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_get_utc_intervals_end_precision_nanos() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-10-29T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-11-01T08:23:45.000000Z")?;
+
+    let with_nano_secs_precision = IntervalGenerator::new()
+        .with_end_precision_nanos(1)
+        .get_intervals(begin, end);
+    assert_eq!(
+        with_nano_secs_precision,
+        IntervalGenerator::new()
+            .with_precision(Duration::nanoseconds(1))
+            .get_intervals(begin, end)
+    );
+
+    for interval in with_nano_secs_precision {
+        assert_eq!(
+            interval.1.time(),
+            NaiveTime::from_hms_nano(23, 59, 59, 999_999_999)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_get_utc_intervals_end_precision_nanos_not_positive() {
+    let _ = IntervalGenerator::new().with_end_precision_nanos(0);
+}
+
+#[test]
+fn test_try_end_precision_nanos() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-10-29T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-11-01T08:23:45.000000Z")?;
+
+    for nanos in [0, -1] {
+        assert!(matches!(
+            IntervalGenerator::new().try_with_end_precision_nanos(nanos),
+            Err(IntervalError::NonPositivePrecision(n)) if n == nanos
+        ));
+    }
+    assert!(matches!(
+        IntervalGenerator::new().try_with_end_precision_nanos(86_400_000_000_000),
+        Err(IntervalError::PrecisionTooLarge)
+    ));
+    assert_eq!(
+        IntervalGenerator::new()
+            .try_with_end_precision_nanos(1)?
+            .get_intervals(begin, end),
+        IntervalGenerator::new()
+            .with_end_precision_nanos(1)
+            .get_intervals(begin, end)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_precision_too_large() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    for precision in [Duration::hours(24), Duration::hours(25)] {
+        assert!(matches!(
+            IntervalGenerator::new().try_with_precision(precision),
+            Err(IntervalError::PrecisionTooLarge)
+        ));
+        assert_eq!(
+            IntervalGenerator::new()
+                .with_precision(precision)
+                .get_intervals_checked(begin, end),
+            Err(IntervalError::PrecisionTooLarge)
+        );
+    }
+
+    // Fine for a grouping with longer intervals
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .try_with_precision(Duration::hours(24))?;
+    assert_eq!(inter_gen.get_intervals_checked(begin, end)?.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_end_precision_iso() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    for (iso_precision, precision) in [
+        ("PT1S", Duration::seconds(1)),
+        ("PT0.001S", Duration::milliseconds(1)),
+        ("PT0.000000001S", Duration::nanoseconds(1)),
+        ("PT1M30.5S", Duration::milliseconds(90_500)),
+        ("P1DT2H", Duration::hours(26)),
+    ] {
+        assert_eq!(
+            IntervalGenerator::new()
+                .with_grouping(Grouping::PerWeek)
+                .with_end_precision_iso(iso_precision)?
+                .get_intervals(begin, end),
+            IntervalGenerator::new()
+                .with_grouping(Grouping::PerWeek)
+                .with_precision(precision)
+                .get_intervals(begin, end)
+        );
+    }
+
+    for malformed in [
+        "",
+        "P",
+        "PT",
+        "1S",
+        "PT1",
+        "PT-1S",
+        "PT1.S",
+        "PT0.0000000001S",
+        "P1W",
+        "PT1S1M",
+    ] {
+        assert_eq!(
+            IntervalGenerator::new()
+                .with_end_precision_iso(malformed)
+                .err(),
+            Some(IntervalError::InvalidDuration(malformed.to_owned()))
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_zero_precision_shares_boundaries_of_weeks_and_months() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    for grouping in [Grouping::PerWeek, Grouping::PerMonth] {
+        // Without end precision, the intervals are half-open and adjacent
+        // intervals share their boundary
+        let half_open_intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_precision(Duration::zero())
+            .get_intervals(begin, end);
+        for window in half_open_intervals.windows(2) {
+            assert_eq!(window[0].1, window[1].0);
+        }
+
+        // With end precision, the intervals include their end which is the
+        // end precision before the next boundary
+        let inclusive_intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .get_intervals(begin, end);
+        for window in inclusive_intervals.windows(2) {
+            assert_eq!(window[0].1 + Duration::milliseconds(1), window[1].0);
+        }
+
+        assert_eq!(half_open_intervals.len(), inclusive_intervals.len());
+        for (half_open_interval, inclusive_interval) in
+            half_open_intervals.iter().zip(inclusive_intervals.iter())
+        {
+            assert_eq!(half_open_interval.0, inclusive_interval.0);
+            assert_eq!(
+                half_open_interval.1,
+                inclusive_interval.1 + Duration::milliseconds(1)
+            );
+        }
+    }
+
+    let monthly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_precision(Duration::zero())
+        .get_intervals(begin, end);
+    assert_eq!(
+        monthly_intervals.last().unwrap().1,
+        Utc.ymd(2022, 7, 1).and_hms(0, 0, 0)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_typed_precision_matches_dynamic() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-08-27T09:31:12.000000Z")?;
+
+    assert_eq!(std::mem::size_of::<Micros>(), 0);
+    for grouping in [Grouping::PerDay, Grouping::PerWeek, Grouping::PerMonth] {
+        let inter_gen = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_offset_west_secs(-7200);
+
+        for (typed_intervals, precision) in [
+            (
+                inter_gen
+                    .clone()
+                    .with_typed_precision(Millis)
+                    .get_intervals(begin, end),
+                Millis.end_precision(),
+            ),
+            (
+                inter_gen
+                    .clone()
+                    .with_typed_precision(Micros)
+                    .get_intervals(begin, end),
+                Micros.end_precision(),
+            ),
+            (
+                inter_gen
+                    .clone()
+                    .with_typed_precision(Nanos)
+                    .get_intervals(begin, end),
+                Nanos.end_precision(),
+            ),
+        ] {
+            assert_eq!(
+                typed_intervals,
+                inter_gen
+                    .clone()
+                    .with_precision(precision)
+                    .get_intervals(begin, end)
+            );
+        }
+    }
+    assert_eq!(Micros.end_precision(), Duration::microseconds(1));
+
+    Ok(())
+}
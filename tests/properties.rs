@@ -0,0 +1,311 @@
+mod common;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use chrono_intervals::{
+    get_interval_label, interval_from_label, AnchorMode, Grouping, IntervalGenerator,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use common::random_time;
+
+const NUM_CASES: usize = 2000;
+
+fn random_grouping(rng: &mut impl Rng) -> Grouping {
+    match rng.gen_range(0..7) {
+        0 => Grouping::PerQuarterHour,
+        1 => Grouping::PerDay,
+        2 => Grouping::PerWeek,
+        3 => Grouping::PerTwoWeeks,
+        4 => Grouping::PerMonth,
+        5 => Grouping::PerRetail445,
+        _ => Grouping::PerYear,
+    }
+}
+
+#[test]
+fn test_interval_invariants_on_random_input() {
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+
+    for _ in 0..NUM_CASES {
+        let grouping = random_grouping(&mut rng);
+        let begin = random_time(&mut rng, 1950);
+        let end = begin + Duration::minutes(15) * rng.gen_range(0..grouping_steps(&grouping));
+        let offset_west_seconds = rng.gen_range(-14 * 3600..=14 * 3600);
+        let end_precision = match rng.gen_range(0..3) {
+            0 => Duration::nanoseconds(1),
+            1 => Duration::milliseconds(1),
+            _ => Duration::seconds(1),
+        };
+        let extend_begin = rng.gen_bool(0.5);
+        let extend_end = rng.gen_bool(0.5);
+        let case = format!(
+            "{:?} from {} to {} with offset {}s, precision {}, extended {}/{}",
+            grouping, begin, end, offset_west_seconds, end_precision, extend_begin, extend_end
+        );
+
+        let mut inter_gen = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_offset_west_secs(offset_west_seconds)
+            .with_precision(end_precision);
+        if !extend_begin {
+            inter_gen = inter_gen.without_extended_begin();
+        }
+        if !extend_end {
+            inter_gen = inter_gen.without_extended_end();
+        }
+        let intervals = inter_gen.get_intervals(begin, end);
+
+        // Intervals are non-empty and adjacent intervals are `end_precision` apart
+        for interval in intervals.iter() {
+            assert!(interval.0 < interval.1, "{}", case);
+        }
+        for pair in intervals.windows(2) {
+            assert_eq!(pair[0].1 + end_precision, pair[1].0, "{}", case);
+        }
+
+        // The intervals cover the range if extended and are enclosed otherwise
+        if let (Some(first), Some(last)) = (intervals.first(), intervals.last()) {
+            match extend_begin {
+                true => assert!(first.0 <= begin, "{}", case),
+                false => assert!(first.0 >= begin, "{}", case),
+            }
+            match extend_end {
+                true => assert!(last.1 >= end, "{}", case),
+                false => assert!(last.1 < end, "{}", case),
+            }
+        } else {
+            assert!(begin == end || !extend_begin || !extend_end, "{}", case);
+        }
+
+        // Every interval is aligned to the boundaries of its label
+        for interval in intervals.iter() {
+            let label = get_interval_label(interval, &grouping, offset_west_seconds);
+            let (label_begin, label_end) =
+                interval_from_label(&label, &grouping, offset_west_seconds).unwrap();
+            assert_eq!(label_begin, interval.0, "{} label {}", case, label);
+            assert_eq!(
+                label_end + Duration::milliseconds(1) - end_precision,
+                interval.1,
+                "{} label {}",
+                case,
+                label
+            );
+        }
+    }
+}
+
+#[test]
+fn test_extended_intervals_contain_range_on_random_input() {
+    let mut rng = StdRng::seed_from_u64(0xc0ffee);
+
+    for _ in 0..NUM_CASES {
+        let grouping = random_grouping(&mut rng);
+        let begin = random_time(&mut rng, 1950);
+        let end = begin + Duration::minutes(15) * rng.gen_range(1..grouping_steps(&grouping));
+        let offset_west_seconds = rng.gen_range(-14 * 3600..=14 * 3600);
+
+        let intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_offset_west_secs(offset_west_seconds)
+            .get_intervals(begin, end);
+
+        // Both `begin` and `end` are contained in an interval
+        for timestamp in [begin, end] {
+            assert!(
+                intervals
+                    .iter()
+                    .any(|interval| interval.0 <= timestamp && timestamp <= interval.1),
+                "{:?} from {} to {} with offset {}s does not contain {}",
+                grouping,
+                begin,
+                end,
+                offset_west_seconds,
+                timestamp.with_timezone(&Utc),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_first_interval_on_random_input() {
+    let mut rng = StdRng::seed_from_u64(0xf1257);
+
+    for _ in 0..NUM_CASES {
+        let (inter_gen, begin, end, case) = random_generator(&mut rng);
+        assert_eq!(
+            inter_gen.first_interval(begin, end),
+            inter_gen.get_intervals(begin, end).first().copied(),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn test_last_interval_on_random_input() {
+    let mut rng = StdRng::seed_from_u64(0x1a57);
+
+    for _ in 0..NUM_CASES {
+        let (inter_gen, begin, end, case) = random_generator(&mut rng);
+        assert_eq!(
+            inter_gen.last_interval(begin, end),
+            inter_gen.get_intervals(begin, end).last().copied(),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn test_first_and_last_interval_near_max_with_east_offset() {
+    let end = DateTime::<Utc>::MAX_UTC - Duration::hours(1);
+    let begin = end - Duration::days(3);
+
+    // The local time of `end` is out of range 14 hours east of UTC
+    for grouping in [
+        Grouping::PerQuarterHour,
+        Grouping::PerDay,
+        Grouping::PerWeek,
+        Grouping::PerTwoWeeks,
+        Grouping::PerMonth,
+        Grouping::PerRetail445,
+        Grouping::PerYear,
+    ] {
+        for inter_gen in [
+            IntervalGenerator::new(),
+            IntervalGenerator::new().without_extended_end(),
+        ] {
+            let inter_gen = inter_gen
+                .with_grouping(grouping)
+                .with_offset_west_secs(-14 * 3600);
+            let intervals = inter_gen.get_intervals(begin, end);
+            assert_eq!(
+                inter_gen.first_interval(begin, end),
+                intervals.first().copied(),
+                "{grouping:?}"
+            );
+            assert_eq!(
+                inter_gen.last_interval(begin, end),
+                intervals.last().copied(),
+                "{grouping:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_intervals_near_min_and_max_with_offsets() {
+    let ranges = [
+        (
+            DateTime::<Utc>::MIN_UTC + Duration::minutes(5),
+            DateTime::<Utc>::MIN_UTC + Duration::days(80),
+        ),
+        (
+            DateTime::<Utc>::MAX_UTC - Duration::days(80),
+            DateTime::<Utc>::MAX_UTC - Duration::minutes(5),
+        ),
+    ];
+
+    for (begin, end) in ranges {
+        for offset_west_seconds in [-14 * 3600, -600, 600, 14 * 3600] {
+            for grouping in [
+                Grouping::PerQuarterHour,
+                Grouping::PerDay,
+                Grouping::PerWeek,
+                Grouping::PerTwoWeeks,
+                Grouping::PerMonth,
+                Grouping::PerRetail445,
+                Grouping::PerYear,
+            ] {
+                for inter_gen in [
+                    IntervalGenerator::new(),
+                    IntervalGenerator::new()
+                        .without_extended_begin()
+                        .without_extended_end(),
+                    IntervalGenerator::new().with_anchor_mode(AnchorMode::Begin),
+                ] {
+                    let inter_gen = inter_gen
+                        .with_grouping(grouping)
+                        .with_offset_west_secs(offset_west_seconds);
+                    let case =
+                        format!("{grouping:?} from {begin} with offset {offset_west_seconds}s");
+                    let intervals = inter_gen.get_intervals(begin, end);
+                    for interval in &intervals {
+                        assert!(interval.0 <= interval.1, "{case}");
+                    }
+                    for window in intervals.windows(2) {
+                        assert!(window[0].1 < window[1].0, "{case}");
+                    }
+                    assert_eq!(
+                        inter_gen.first_interval(begin, end),
+                        intervals.first().copied(),
+                        "{case}"
+                    );
+                    assert_eq!(
+                        inter_gen.last_interval(begin, end),
+                        intervals.last().copied(),
+                        "{case}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Get a generator with random options and a random range.
+fn random_generator(
+    rng: &mut impl Rng,
+) -> (IntervalGenerator, DateTime<Utc>, DateTime<Utc>, String) {
+    let grouping = random_grouping(rng);
+    let begin = random_time(rng, 1950);
+    let end = begin + Duration::minutes(15) * rng.gen_range(0..grouping_steps(&grouping));
+    let offset_west_seconds = rng.gen_range(-14 * 3600..=14 * 3600);
+    let mut inter_gen = IntervalGenerator::new()
+        .with_grouping(grouping)
+        .with_offset_west_secs(offset_west_seconds);
+    let mut options = vec![];
+    if rng.gen_bool(0.5) {
+        inter_gen = inter_gen.without_extended_begin();
+        options.push("without extended begin");
+    }
+    if rng.gen_bool(0.5) {
+        inter_gen = inter_gen.without_extended_end();
+        options.push("without extended end");
+    }
+    match rng.gen_range(0..5) {
+        0 => {
+            inter_gen = inter_gen.with_anchor_mode(AnchorMode::Begin);
+            options.push("anchored at begin");
+        }
+        1 => {
+            inter_gen = inter_gen.with_anchor(random_time(rng, 1950));
+            options.push("anchored at random time");
+        }
+        2 => {
+            inter_gen = inter_gen
+                .with_fiscal_year_start(4, 1)
+                .with_day_start_time(NaiveTime::from_hms(9, 30, 0));
+            options.push("with fiscal year and day start");
+        }
+        3 => {
+            inter_gen = inter_gen.with_partial_first_week();
+            options.push("with partial first week");
+        }
+        _ => {}
+    }
+    let case = format!(
+        "{:?} from {} to {} with offset {}s, {:?}",
+        grouping, begin, end, offset_west_seconds, options
+    );
+    (inter_gen, begin, end, case)
+}
+
+/// Number of quarter hours to cover a few intervals of the grouping.
+fn grouping_steps(grouping: &Grouping) -> i32 {
+    match grouping {
+        Grouping::PerQuarterHour => 4 * 24,
+        Grouping::PerDay => 4 * 24 * 10,
+        Grouping::PerWeek | Grouping::PerTwoWeeks => 4 * 24 * 70,
+        Grouping::PerMonth | Grouping::PerRetail445 => 4 * 24 * 150,
+        Grouping::PerYear => 4 * 24 * 1500,
+    }
+}
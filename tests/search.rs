@@ -0,0 +1,212 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{
+    cmp_by_start, filter_overlapping, find_interval_containing, normalize, Error, IntervalGenerator,
+};
+
+#[test]
+fn test_find_interval_containing_over_a_year() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-01T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-12-31T08:23:45.000000Z")?;
+
+    let mut daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    assert_eq!(daily_intervals.len(), 365);
+
+    let mid_year = Utc.ymd(2022, 7, 2).and_hms(13, 37, 0);
+    assert_eq!(
+        find_interval_containing(&daily_intervals, &mid_year),
+        Some(182)
+    );
+
+    // Interval boundaries are contained
+    let index = 182;
+    assert_eq!(
+        find_interval_containing(&daily_intervals, &daily_intervals[index].0),
+        Some(index)
+    );
+    assert_eq!(
+        find_interval_containing(&daily_intervals, &daily_intervals[index].1),
+        Some(index)
+    );
+
+    // The gap between intervals and timestamps outside are not contained
+    let in_gap = daily_intervals[index].1 + Duration::microseconds(500);
+    assert_eq!(find_interval_containing(&daily_intervals, &in_gap), None);
+    let before = daily_intervals[0].0 - Duration::seconds(1);
+    assert_eq!(find_interval_containing(&daily_intervals, &before), None);
+    let after = daily_intervals[364].1 + Duration::seconds(1);
+    assert_eq!(find_interval_containing(&daily_intervals, &after), None);
+
+    // Sorting a shuffled list by start restores the order
+    let sorted_intervals = daily_intervals.clone();
+    daily_intervals.reverse();
+    daily_intervals.sort_by(cmp_by_start);
+    assert_eq!(daily_intervals, sorted_intervals);
+
+    Ok(())
+}
+
+#[test]
+fn test_normalize_unsorted_overlapping() {
+    let at = |day, hour| Utc.ymd(2022, 6, day).and_hms(hour, 0, 0);
+    let intervals = vec![
+        (at(5, 0), at(5, 12)),
+        (at(1, 0), at(2, 0)),
+        (at(3, 0), at(4, 0)),
+        (at(1, 12), at(1, 18)),
+        (at(2, 0), at(2, 6)),
+        (at(3, 12), at(4, 12)),
+    ];
+
+    let end_precision = Duration::milliseconds(1);
+    assert_eq!(
+        normalize(&intervals, end_precision),
+        vec![
+            (at(1, 0), at(2, 6)),
+            (at(3, 0), at(4, 12)),
+            (at(5, 0), at(5, 12)),
+        ]
+    );
+    assert_eq!(
+        normalize(&normalize(&intervals, end_precision), end_precision),
+        normalize(&intervals, end_precision)
+    );
+}
+
+#[test]
+fn test_normalize_merges_adjacent_intervals() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-27T09:31:12.000000Z")?;
+
+    // Generated intervals are `end_precision` apart and merged into one
+    let mut daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    let merged_interval = (daily_intervals[0].0, daily_intervals.last().unwrap().1);
+    daily_intervals.reverse();
+    assert_eq!(
+        normalize(&daily_intervals, Duration::milliseconds(1)),
+        vec![merged_interval]
+    );
+
+    // A gap of more than `end_precision` is kept
+    let at = |hour, milli| Utc.ymd(2022, 6, 1).and_hms_milli(hour, 0, 0, milli);
+    let intervals = vec![(at(0, 0), at(1, 0)), (at(1, 2), at(2, 0))];
+    assert_eq!(normalize(&intervals, Duration::milliseconds(1)), intervals);
+    assert_eq!(
+        normalize(&intervals, Duration::milliseconds(2)),
+        vec![(at(0, 0), at(2, 0))]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_filter_overlapping_partial_query() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-01T00:00:00.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-30T12:00:00.000000Z")?;
+    let daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    assert_eq!(daily_intervals.len(), 30);
+
+    // The query starts within the 10th and ends within the 12th day
+    let query = (
+        Utc.ymd(2022, 6, 10).and_hms(18, 0, 0),
+        Utc.ymd(2022, 6, 12).and_hms(6, 0, 0),
+    );
+    assert_eq!(
+        filter_overlapping(&daily_intervals, &query),
+        daily_intervals[9..12]
+    );
+
+    // A query touching the last and the first moment of days includes them
+    let query = (
+        Utc.ymd(2022, 6, 10).and_hms_milli(23, 59, 59, 999),
+        Utc.ymd(2022, 6, 12).and_hms(0, 0, 0),
+    );
+    assert_eq!(
+        filter_overlapping(&daily_intervals, &query),
+        daily_intervals[9..12]
+    );
+
+    // A query in the gap between two days overlaps neither of them
+    let query = (
+        Utc.ymd(2022, 6, 10).and_hms_milli(23, 59, 59, 999) + Duration::microseconds(500),
+        Utc.ymd(2022, 6, 10).and_hms_milli(23, 59, 59, 999) + Duration::microseconds(600),
+    );
+    assert!(filter_overlapping(&daily_intervals, &query).is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_interval_at_offset() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+    let inter_gen = IntervalGenerator::new();
+
+    let daily_intervals = inter_gen.get_intervals(begin, end);
+    assert_eq!(
+        inter_gen.interval_at_offset(begin, end, Duration::hours(36)),
+        Some(daily_intervals[1])
+    );
+    assert_eq!(
+        inter_gen.interval_at_offset(begin, end, Duration::zero()),
+        Some(daily_intervals[0])
+    );
+    assert_eq!(
+        inter_gen.interval_at_offset(begin, end, end - begin),
+        Some(daily_intervals[2])
+    );
+
+    // Beyond `end` and before `begin`
+    assert_eq!(
+        inter_gen.interval_at_offset(begin, end, Duration::hours(50)),
+        None
+    );
+    assert_eq!(
+        inter_gen.interval_at_offset(begin, end, Duration::hours(-1)),
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_get_intervals_for_ranges() -> Result<(), Error> {
+    let ranges = [
+        (
+            DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?,
+            DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?,
+        ),
+        (
+            DateTime::parse_from_rfc3339("2022-06-30T08:23:45.000000Z")?,
+            DateTime::parse_from_rfc3339("2022-07-01T09:31:12.000000Z")?,
+        ),
+        // Overlaps with the first range on June 26th and 27th
+        (
+            DateTime::parse_from_rfc3339("2022-06-26T08:23:45.000000Z")?,
+            DateTime::parse_from_rfc3339("2022-06-28T09:31:12.000000Z")?,
+        ),
+    ];
+
+    let inter_gen = IntervalGenerator::new();
+    let intervals = inter_gen.get_intervals_for_ranges(&ranges);
+    assert_eq!(
+        intervals
+            .iter()
+            .map(|interval| interval.0)
+            .collect::<Vec<_>>(),
+        vec![
+            Utc.ymd(2022, 6, 25).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 27).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 28).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 30).and_hms(0, 0, 0),
+            Utc.ymd(2022, 7, 1).and_hms(0, 0, 0),
+        ]
+    );
+    assert_eq!(
+        inter_gen.get_intervals_for_ranges(&ranges[..1]),
+        inter_gen.get_intervals(ranges[0].0, ranges[0].1)
+    );
+    assert!(inter_gen.get_intervals_for_ranges::<Utc>(&[]).is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{Error, IntervalGenerator};
+
+#[test]
+fn test_sliding_weekly_windows_stepping_daily() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-20T00:00:00.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-30T00:00:00.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().with_sliding(Duration::days(7), Duration::days(1));
+
+    // Only the windows ending before `end` without extension
+    let windows = inter_gen
+        .clone()
+        .without_extension()
+        .get_intervals(begin, end);
+    assert_eq!(windows.len(), 4);
+    assert_eq!(
+        windows.first(),
+        Some(&(
+            Utc.ymd(2022, 6, 20).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(23, 59, 59, 999),
+        ))
+    );
+    assert_eq!(
+        windows.last(),
+        Some(&(
+            Utc.ymd(2022, 6, 23).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 29).and_hms_milli(23, 59, 59, 999),
+        ))
+    );
+    for window in windows.iter() {
+        assert_eq!(
+            window.1 - window.0 + Duration::milliseconds(1),
+            Duration::days(7)
+        );
+    }
+    for pair in windows.windows(2) {
+        assert_eq!(pair[1].0 - pair[0].0, Duration::days(1));
+        assert_eq!(
+            pair[0].1 - pair[1].0 + Duration::milliseconds(1),
+            Duration::days(6)
+        );
+    }
+
+    // With extension, the window containing `end` is included as well
+    let windows = inter_gen.get_intervals(begin, end);
+    assert_eq!(windows.len(), 5);
+    assert_eq!(windows[4].0, Utc.ymd(2022, 6, 24).and_hms(0, 0, 0));
+
+    Ok(())
+}
+
+#[test]
+fn test_sliding_neighbor_intervals_use_the_grouping() -> Result<(), Error> {
+    let now = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?.with_timezone(&Utc);
+
+    // Stepping back is not supported by strategies, the daily grouping is used
+    let inter_gen = IntervalGenerator::new();
+    let sliding_gen = inter_gen
+        .clone()
+        .with_sliding(Duration::days(7), Duration::days(1));
+    assert_eq!(
+        sliding_gen.previous_interval(now),
+        (
+            Utc.ymd(2022, 6, 24).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 24).and_hms_milli(23, 59, 59, 999),
+        )
+    );
+    assert_eq!(sliding_gen.next_interval(now), inter_gen.next_interval(now));
+    assert_eq!(
+        sliding_gen.trailing_periods(now, 3),
+        inter_gen.trailing_periods(now, 3)
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "sliding step must be positive")]
+fn test_sliding_step_not_positive() {
+    let _ = IntervalGenerator::new().with_sliding(Duration::days(7), Duration::zero());
+}
+
+#[test]
+#[should_panic(expected = "sliding window must be positive")]
+fn test_sliding_window_not_positive() {
+    let _ = IntervalGenerator::new().with_sliding(Duration::days(-7), Duration::days(1));
+}
@@ -0,0 +1,146 @@
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+use chrono_intervals::{Error, Grouping, GroupingStrategy, IntervalGenerator, TimeInterval};
+
+/// Intervals of 10 hours, aligned to the Unix epoch.
+struct EveryTenHours;
+
+impl GroupingStrategy for EveryTenHours {
+    fn initial(
+        &self,
+        begin: DateTime<FixedOffset>,
+        local_timezone: &FixedOffset,
+        end_precision: Duration,
+        extend_begin: bool,
+    ) -> TimeInterval<FixedOffset> {
+        let period_secs = Duration::hours(10).num_seconds();
+        let begin_secs = begin.timestamp().div_euclid(period_secs) * period_secs;
+        let mut init_begin = Utc
+            .timestamp_opt(begin_secs, 0)
+            .unwrap()
+            .with_timezone(local_timezone);
+        if !extend_begin {
+            init_begin += Duration::hours(10);
+        }
+        (init_begin, init_begin + Duration::hours(10) - end_precision)
+    }
+
+    fn next(
+        &self,
+        cur_begin: DateTime<FixedOffset>,
+        end_precision: Duration,
+    ) -> TimeInterval<FixedOffset> {
+        let cur_begin = cur_begin + Duration::hours(10);
+        (cur_begin, cur_begin + Duration::hours(10) - end_precision)
+    }
+}
+
+#[test]
+fn test_custom_strategy_every_ten_hours() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-26T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().with_strategy(Box::new(EveryTenHours));
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2022, 6, 25).and_hms(8, 0, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(17, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 25).and_hms(18, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(3, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 26).and_hms(4, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(13, 59, 59, 999),
+        ),
+    ];
+    assert_eq!(inter_gen.get_intervals(begin, end), expected_intervals);
+    assert_eq!(
+        inter_gen.get_intervals_paged(begin, end, 1, 2),
+        expected_intervals[1..3]
+    );
+    assert_eq!(inter_gen.get_n_intervals(begin, 2), expected_intervals[..2]);
+    assert_eq!(
+        inter_gen.without_extension().get_intervals(begin, end),
+        expected_intervals[1..2]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_groupings_as_strategy() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-09-26T12:23:45.000000Z")?;
+
+    let groupings = [
+        Grouping::PerQuarterHour,
+        Grouping::PerDay,
+        Grouping::PerWeek,
+        Grouping::PerTwoWeeks,
+        Grouping::PerMonth,
+        Grouping::PerYear,
+        Grouping::PerRetail445,
+    ];
+    for grouping in groupings {
+        assert_eq!(
+            IntervalGenerator::new()
+                .with_offset_west_secs(-7200)
+                .with_strategy(Box::new(grouping))
+                .get_intervals(begin, end),
+            IntervalGenerator::new()
+                .with_offset_west_secs(-7200)
+                .with_grouping(grouping)
+                .get_intervals(begin, end),
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_grouping_strategy_outputs_per_day_week_month() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-01-31T08:23:45.000000+01:00")?;
+    let local_timezone = FixedOffset::east(3600);
+    let precision = Duration::milliseconds(1);
+
+    for (grouping, expected_initial, expected_next) in [
+        (
+            Grouping::PerDay,
+            ("2022-01-31T00:00:00+01:00", "2022-01-31T23:59:59.999+01:00"),
+            ("2022-02-01T00:00:00+01:00", "2022-02-01T23:59:59.999+01:00"),
+        ),
+        (
+            Grouping::PerWeek,
+            ("2022-01-31T00:00:00+01:00", "2022-02-06T23:59:59.999+01:00"),
+            ("2022-02-07T00:00:00+01:00", "2022-02-13T23:59:59.999+01:00"),
+        ),
+        (
+            Grouping::PerMonth,
+            ("2022-01-01T00:00:00+01:00", "2022-01-31T23:59:59.999+01:00"),
+            ("2022-02-01T00:00:00+01:00", "2022-02-28T23:59:59.999+01:00"),
+        ),
+    ] {
+        let parse = |interval: (&str, &str)| -> Result<TimeInterval<FixedOffset>, Error> {
+            Ok((
+                DateTime::parse_from_rfc3339(interval.0)?,
+                DateTime::parse_from_rfc3339(interval.1)?,
+            ))
+        };
+        let initial = grouping.initial(begin, &local_timezone, precision, true);
+        assert_eq!(initial, parse(expected_initial)?);
+        assert_eq!(grouping.next(initial.0, precision), parse(expected_next)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_generator_with_strategy_is_unwind_safe() {
+    fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>(_: &T) {}
+
+    assert_unwind_safe(&IntervalGenerator::new().with_strategy(Box::new(EveryTenHours)));
+    assert_unwind_safe(&IntervalGenerator::new().with_wall_clock_days(Utc));
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{Error, Grouping, IntervalGenerator};
+
+#[test]
+fn test_per_day_subwindow() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let working_hours = IntervalGenerator::new()
+        .with_subwindow(Duration::hours(8), Duration::hours(8))
+        .get_intervals(begin, end);
+    assert_eq!(
+        working_hours,
+        vec![
+            (
+                Utc.ymd(2022, 6, 25).and_hms(8, 0, 0),
+                Utc.ymd(2022, 6, 25).and_hms_milli(15, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 26).and_hms(8, 0, 0),
+                Utc.ymd(2022, 6, 26).and_hms_milli(15, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 27).and_hms(8, 0, 0),
+                Utc.ymd(2022, 6, 27).and_hms_milli(15, 59, 59, 999),
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_per_week_subwindow() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-15T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-22T08:23:45.000000Z")?;
+
+    // Weekdays only
+    let weekdays = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .with_subwindow(Duration::zero(), Duration::days(5))
+        .get_intervals(begin, end);
+    assert_eq!(
+        weekdays,
+        vec![
+            (
+                Utc.ymd(2022, 6, 13).and_hms(0, 0, 0),
+                Utc.ymd(2022, 6, 17).and_hms_milli(23, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 20).and_hms(0, 0, 0),
+                Utc.ymd(2022, 6, 24).and_hms_milli(23, 59, 59, 999),
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_subwindow_exceeding_grouping() {
+    let _ = IntervalGenerator::new().with_subwindow(Duration::hours(20), Duration::hours(8));
+}
+
+#[test]
+#[should_panic]
+fn test_subwindow_exceeding_later_grouping() {
+    let _ = IntervalGenerator::new()
+        .with_grouping(Grouping::PerWeek)
+        .with_subwindow(Duration::days(2), Duration::days(2))
+        .with_grouping(Grouping::PerQuarterHour);
+}
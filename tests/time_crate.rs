@@ -0,0 +1,82 @@
+#![cfg(feature = "time")]
+use chrono::Duration;
+use chrono_intervals::{time_compat, Error, Grouping, IntervalGenerator};
+use time::{Date, Month, OffsetDateTime};
+
+fn utc_datetime(
+    year: i32,
+    month: Month,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    milli: u16,
+) -> Result<OffsetDateTime, Error> {
+    Ok(Date::from_calendar_date(year, month, day)?
+        .with_hms_milli(hour, minute, second, milli)?
+        .assume_utc())
+}
+
+#[test]
+fn test_time_crate_per_day() -> Result<(), Error> {
+    let begin = utc_datetime(2022, Month::June, 25, 8, 23, 45, 0)?;
+    let end = utc_datetime(2022, Month::June, 27, 9, 31, 12, 0)?;
+
+    let expected_intervals = vec![
+        (
+            utc_datetime(2022, Month::June, 25, 0, 0, 0, 0)?,
+            utc_datetime(2022, Month::June, 25, 23, 59, 59, 999)?,
+        ),
+        (
+            utc_datetime(2022, Month::June, 26, 0, 0, 0, 0)?,
+            utc_datetime(2022, Month::June, 26, 23, 59, 59, 999)?,
+        ),
+        (
+            utc_datetime(2022, Month::June, 27, 0, 0, 0, 0)?,
+            utc_datetime(2022, Month::June, 27, 23, 59, 59, 999)?,
+        ),
+    ];
+
+    assert_eq!(
+        IntervalGenerator::new().get_offset_date_time_intervals(begin, end),
+        expected_intervals
+    );
+    assert_eq!(
+        time_compat::get_extended_utc_intervals(begin, end, &Grouping::PerDay, 0),
+        expected_intervals
+    );
+    assert_eq!(
+        time_compat::get_utc_intervals_opts(
+            begin,
+            end,
+            &Grouping::PerDay,
+            0,
+            Duration::milliseconds(1),
+            false,
+            false,
+        ),
+        expected_intervals[1..2]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_time_crate_intervals_out_of_range_are_left_out() -> Result<(), Error> {
+    let begin = Date::MIN.midnight().assume_utc();
+    let end = begin + time::Duration::hours(12);
+
+    // The extended year begins before the first date of `time`.
+    assert_eq!(
+        time_compat::get_extended_utc_intervals(begin, end, &Grouping::PerYear, -3600),
+        vec![]
+    );
+    assert_eq!(
+        time_compat::get_extended_utc_intervals(begin, end, &Grouping::PerDay, 0),
+        vec![(
+            begin,
+            utc_datetime(-9999, Month::January, 1, 23, 59, 59, 999)?
+        )]
+    );
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_intervals::{
+    interval_from_millis, interval_to_millis, Error, Grouping, IntervalGenerator,
+};
+
+#[test]
+fn test_interval_millis_round_trip() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000Z")?;
+
+    let daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    assert_eq!(
+        interval_to_millis(&daily_intervals[0]),
+        (1656115200000, 1656201599999)
+    );
+    for interval in daily_intervals {
+        let (begin_ms, end_ms) = interval_to_millis(&interval);
+        assert_eq!(interval_from_millis(begin_ms, end_ms), interval);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_interval_to_millis_from_other_timezone() -> Result<(), Error> {
+    let interval = (
+        DateTime::parse_from_rfc3339("2022-06-25T00:00:00.000000+02:00")?,
+        DateTime::parse_from_rfc3339("2022-06-25T23:59:59.999000+02:00")?,
+    );
+
+    let (begin_ms, end_ms) = interval_to_millis(&interval);
+    assert_eq!(
+        interval_from_millis(begin_ms, end_ms),
+        (
+            Utc.ymd(2022, 6, 24).and_hms(22, 0, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(21, 59, 59, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_bucket_timestamps() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-27T09:31:12.000000Z")?;
+
+    let inter_gen = IntervalGenerator::new().with_grouping(Grouping::PerDay);
+    let timestamps = inter_gen.bucket_timestamps(begin, end);
+    assert_eq!(timestamps.len(), 33);
+    assert_eq!(
+        timestamps[0],
+        Utc.ymd(2022, 6, 25).and_hms(0, 0, 0).timestamp()
+    );
+    for pair in timestamps.windows(2) {
+        assert_eq!(pair[1] - pair[0], 24 * 3600);
+    }
+
+    // Aligned to the local midnight with an offset
+    let timestamps = inter_gen
+        .with_offset_west_secs(-7200)
+        .bucket_timestamps(begin, end);
+    assert!(timestamps
+        .iter()
+        .all(|timestamp| (timestamp + 7200) % (24 * 3600) == 0));
+
+    Ok(())
+}
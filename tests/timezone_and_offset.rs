@@ -1,5 +1,11 @@
-use chrono::{DateTime, TimeZone, Utc};
-use chrono_intervals::{Error, Grouping, IntervalGenerator};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike, Utc,
+};
+use chrono_intervals::{
+    count_weeks_opts, get_extended_utc_intervals, get_interval_label, get_utc_intervals_opts,
+    interval_from_label, offset_for, Error, Grouping, IntervalConfig, IntervalError,
+    IntervalGenerator,
+};
 
 #[test]
 fn test_utc_begin_end_to_utc() -> Result<(), Error> {
@@ -135,3 +141,319 @@ fn test_pdt_offset_days() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_local_and_utc_intervals_differ_by_offset() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-10T12:23:45.000000-07:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-08-26T12:23:45.000000-07:00")?;
+    let pdt_offset_west_seconds = 7 * 3600;
+
+    let inter_gen = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_offset_west_secs(pdt_offset_west_seconds);
+    let intervals_with_local = inter_gen.get_intervals_with_local(begin, end);
+
+    assert_eq!(intervals_with_local.len(), 3);
+    for (local_interval, utc_interval) in intervals_with_local {
+        // Same instants, but wall clock times shifted by the offset
+        assert_eq!(local_interval.0, utc_interval.0);
+        assert_eq!(local_interval.1, utc_interval.1);
+        assert_eq!(
+            utc_interval.0.naive_utc() - local_interval.0.naive_local(),
+            Duration::seconds(pdt_offset_west_seconds as i64)
+        );
+        assert_eq!(
+            utc_interval.1.naive_utc() - local_interval.1.naive_local(),
+            Duration::seconds(pdt_offset_west_seconds as i64)
+        );
+        assert_eq!(local_interval.0.day(), 1);
+        assert_eq!(local_interval.0.time(), NaiveTime::from_hms(0, 0, 0));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_sub_minute_offset() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-10T12:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-08-26T12:23:45.000000Z")?;
+
+    for grouping in [
+        Grouping::PerQuarterHour,
+        Grouping::PerDay,
+        Grouping::PerWeek,
+        Grouping::PerMonth,
+    ] {
+        // All boundaries are shifted by exactly 30 seconds
+        let intervals = IntervalGenerator::new()
+            .with_grouping(grouping)
+            .with_offset_west_secs(30)
+            .get_intervals(begin, end);
+        for interval in intervals {
+            assert_eq!(interval.0.second(), 30);
+            assert_eq!(interval.0.nanosecond(), 0);
+            assert_eq!(interval.1.second(), 29);
+        }
+    }
+
+    let monthly_intervals = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_offset_west_secs(30)
+        .get_intervals(begin, end);
+    assert_eq!(
+        monthly_intervals.first().unwrap(),
+        &(
+            Utc.ymd(2022, 6, 1).and_hms(0, 0, 30),
+            Utc.ymd(2022, 7, 1).and_hms_milli(0, 0, 29, 999),
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_month_boundaries_carry_the_same_offset() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2021-11-10T12:23:45.000000+05:30")?;
+    let end = DateTime::parse_from_rfc3339("2022-05-26T12:23:45.000000+05:30")?;
+    let ist_offset_west_seconds = -(5 * 3600 + 30 * 60);
+
+    let intervals_with_local = IntervalGenerator::new()
+        .with_grouping(Grouping::PerMonth)
+        .with_offset_west_secs(ist_offset_west_seconds)
+        .get_intervals_with_local(begin, end);
+    assert_eq!(intervals_with_local.len(), 7);
+
+    for (local_interval, utc_interval) in intervals_with_local {
+        assert_eq!(
+            local_interval.0.offset().utc_minus_local(),
+            ist_offset_west_seconds
+        );
+        assert_eq!(local_interval.0.day(), 1);
+        assert_eq!(local_interval.0.time(), NaiveTime::from_hms(0, 0, 0));
+        assert_eq!(utc_interval.0.time(), NaiveTime::from_hms(18, 30, 0));
+        assert_eq!(
+            utc_interval.1.time(),
+            NaiveTime::from_hms_milli(18, 29, 59, 999)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_utc_input_equals_offset_input() {
+    let begin = Utc.ymd(2022, 6, 25).and_hms(8, 23, 45);
+    let end = Utc.ymd(2022, 9, 27).and_hms(9, 31, 12);
+    let zero_offset = FixedOffset::east(0);
+    let pdt_offset = FixedOffset::west(7 * 3600);
+
+    for grouping in [
+        Grouping::PerQuarterHour,
+        Grouping::PerDay,
+        Grouping::PerWeek,
+        Grouping::PerMonth,
+    ] {
+        let inter_gen = IntervalGenerator::new().with_grouping(grouping);
+        let utc_intervals = inter_gen.get_intervals(begin, end);
+        assert_eq!(
+            utc_intervals,
+            inter_gen.get_intervals(
+                begin.with_timezone(&zero_offset),
+                end.with_timezone(&zero_offset)
+            )
+        );
+        assert_eq!(
+            utc_intervals,
+            inter_gen.get_intervals(
+                begin.with_timezone(&pdt_offset),
+                end.with_timezone(&pdt_offset)
+            )
+        );
+    }
+}
+
+#[test]
+fn test_offset_for_common_zones() -> Result<(), Error> {
+    assert_eq!(offset_for("PDT"), Some(7 * 3600));
+    assert_eq!(offset_for("EDT"), Some(4 * 3600));
+    assert_eq!(offset_for("CET"), Some(-3600));
+    assert_eq!(offset_for("CEST"), Some(-7200));
+    assert_eq!(offset_for("IST"), Some(-(5 * 3600 + 30 * 60)));
+    assert_eq!(offset_for("UTC"), Some(0));
+    assert_eq!(offset_for("pdt"), offset_for("PDT"));
+    assert_eq!(offset_for("XYZ"), None);
+
+    // The offset matches the offset of the local time in the zone
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000+02:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000+02:00")?;
+    let daily_intervals = IntervalGenerator::new()
+        .with_offset_west_secs(offset_for("CEST").unwrap())
+        .get_intervals(begin, end);
+    for interval in daily_intervals {
+        assert_eq!(
+            interval.0.with_timezone(begin.offset()).time(),
+            NaiveTime::from_hms(0, 0, 0)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_intervals_with_local_date() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000+07:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000+07:00")?;
+
+    let intervals_with_local_date = IntervalGenerator::new()
+        .with_offset_west_secs(-7 * 3600)
+        .get_intervals_with_local_date(begin, end);
+    assert_eq!(
+        intervals_with_local_date,
+        vec![
+            (
+                NaiveDate::from_ymd(2022, 6, 25),
+                (
+                    Utc.ymd(2022, 6, 24).and_hms(17, 0, 0),
+                    Utc.ymd(2022, 6, 25).and_hms_milli(16, 59, 59, 999),
+                )
+            ),
+            (
+                NaiveDate::from_ymd(2022, 6, 26),
+                (
+                    Utc.ymd(2022, 6, 25).and_hms(17, 0, 0),
+                    Utc.ymd(2022, 6, 26).and_hms_milli(16, 59, 59, 999),
+                )
+            ),
+            (
+                NaiveDate::from_ymd(2022, 6, 27),
+                (
+                    Utc.ymd(2022, 6, 26).and_hms(17, 0, 0),
+                    Utc.ymd(2022, 6, 27).and_hms_milli(16, 59, 59, 999),
+                )
+            ),
+        ]
+    );
+    for (local_date, utc_interval) in intervals_with_local_date {
+        assert_eq!(local_date, utc_interval.0.naive_utc().date().succ());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_begin_and_end_in_different_timezones() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000+02:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-26T20:31:12.000000-07:00")?;
+
+    let expected_intervals = vec![
+        (
+            Utc.ymd(2022, 6, 25).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(23, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 26).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 26).and_hms_milli(23, 59, 59, 999),
+        ),
+        (
+            Utc.ymd(2022, 6, 27).and_hms(0, 0, 0),
+            Utc.ymd(2022, 6, 27).and_hms_milli(23, 59, 59, 999),
+        ),
+    ];
+    let end_utc = end.with_timezone(&Utc);
+    assert_eq!(end_utc, Utc.ymd(2022, 6, 27).and_hms(3, 31, 12));
+
+    assert_eq!(
+        IntervalGenerator::new().get_intervals(begin, end_utc),
+        expected_intervals
+    );
+    assert_eq!(
+        IntervalGenerator::new().get_intervals(begin, end),
+        expected_intervals
+    );
+    assert_eq!(
+        get_extended_utc_intervals(begin, end_utc, &Grouping::PerDay, 0),
+        expected_intervals
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_out_of_range_offsets_do_not_panic() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-07-05T09:31:12.000000Z")?;
+
+    for (offset_west_secs, clamped_secs) in [
+        (86_400, 86_399),
+        (-86_400, -86_399),
+        (i32::MAX, 86_399),
+        (i32::MIN, -86_399),
+    ] {
+        let inter_gen = IntervalGenerator::new().with_offset_west_secs(offset_west_secs);
+        assert_eq!(
+            inter_gen.get_intervals(begin, end),
+            IntervalGenerator::new()
+                .with_offset_west_secs(clamped_secs)
+                .get_intervals(begin, end)
+        );
+        assert_eq!(
+            inter_gen.get_intervals_checked(begin, end),
+            Err(IntervalError::InvalidOffset(offset_west_secs))
+        );
+        assert_eq!(
+            IntervalGenerator::from(IntervalConfig {
+                offset_west_seconds: offset_west_secs,
+                ..IntervalConfig::default()
+            })
+            .get_intervals_checked(begin, end),
+            Err(IntervalError::InvalidOffset(offset_west_secs))
+        );
+
+        assert_eq!(
+            get_utc_intervals_opts(
+                begin,
+                end,
+                &Grouping::PerWeek,
+                offset_west_secs,
+                Duration::milliseconds(1),
+                true,
+                true,
+            ),
+            get_extended_utc_intervals(begin, end, &Grouping::PerWeek, clamped_secs)
+        );
+        assert_eq!(
+            get_extended_utc_intervals(begin, end, &Grouping::PerDay, offset_west_secs),
+            get_extended_utc_intervals(begin, end, &Grouping::PerDay, clamped_secs)
+        );
+        assert_eq!(
+            count_weeks_opts(
+                begin,
+                end,
+                offset_west_secs,
+                Duration::milliseconds(1),
+                true,
+                true
+            ),
+            count_weeks_opts(
+                begin,
+                end,
+                clamped_secs,
+                Duration::milliseconds(1),
+                true,
+                true
+            )
+        );
+
+        let interval = inter_gen.get_intervals(begin, end)[0];
+        assert_eq!(
+            get_interval_label(&interval, &Grouping::PerDay, offset_west_secs),
+            get_interval_label(&interval, &Grouping::PerDay, clamped_secs)
+        );
+        assert_eq!(
+            interval_from_label("2022-06-25", &Grouping::PerDay, offset_west_secs),
+            Err(IntervalError::InvalidOffset(offset_west_secs))
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_intervals::{
+    checked_interval, coverage_bitmap, overlap_fraction, pad_intervals, Error, IntervalError,
+    IntervalGenerator,
+};
+
+#[test]
+fn test_pad_daily_intervals() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-26T09:31:12.000000Z")?;
+
+    let daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    let padded_intervals = pad_intervals(&daily_intervals, Duration::hours(1), Duration::hours(1));
+    assert_eq!(
+        padded_intervals,
+        vec![
+            (
+                Utc.ymd(2022, 6, 24).and_hms(23, 0, 0),
+                Utc.ymd(2022, 6, 26).and_hms_milli(0, 59, 59, 999),
+            ),
+            (
+                Utc.ymd(2022, 6, 25).and_hms(23, 0, 0),
+                Utc.ymd(2022, 6, 27).and_hms_milli(0, 59, 59, 999),
+            ),
+        ]
+    );
+
+    // Consecutive padded intervals overlap
+    assert!(padded_intervals[1].0 < padded_intervals[0].1);
+
+    Ok(())
+}
+
+#[test]
+fn test_overlap_fraction() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000Z")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-28T09:31:12.000000Z")?;
+
+    let daily_intervals = IntervalGenerator::new().get_intervals(begin, end);
+    let day = daily_intervals[1];
+
+    // Full overlap, also for the window exactly matching the interval
+    let window = (daily_intervals[0].0, daily_intervals[2].1);
+    assert_eq!(overlap_fraction(&day, &window), 1.0);
+    assert_eq!(overlap_fraction(&day, &day), 1.0);
+
+    // Half overlap at the beginning and at the end
+    let half_length = (day.1 - day.0) / 2;
+    let window = (daily_intervals[0].0, day.0 + half_length);
+    assert_eq!(overlap_fraction(&day, &window), 0.5);
+    let window = (day.1 - half_length, daily_intervals[2].1);
+    assert_eq!(overlap_fraction(&day, &window), 0.5);
+
+    // No overlap with the neighboring days
+    assert_eq!(overlap_fraction(&day, &daily_intervals[0]), 0.0);
+    assert_eq!(overlap_fraction(&day, &daily_intervals[2]), 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_checked_interval() -> Result<(), Error> {
+    let begin = DateTime::parse_from_rfc3339("2022-06-25T08:23:45.000000-07:00")?;
+    let end = DateTime::parse_from_rfc3339("2022-06-27T09:31:12.000000-07:00")?;
+
+    assert_eq!(
+        checked_interval(begin, end)?,
+        (
+            Utc.ymd(2022, 6, 25).and_hms(15, 23, 45),
+            Utc.ymd(2022, 6, 27).and_hms(16, 31, 12),
+        )
+    );
+    assert_eq!(
+        checked_interval(begin, begin)?,
+        (begin.with_timezone(&Utc), begin.with_timezone(&Utc))
+    );
+    assert_eq!(
+        checked_interval(end, begin),
+        Err(IntervalError::ReversedRange)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_coverage_bitmap_hourly_over_a_day() {
+    let range = (
+        Utc.ymd(2022, 6, 25).and_hms(0, 0, 0),
+        Utc.ymd(2022, 6, 26).and_hms(0, 0, 0),
+    );
+    let intervals = [
+        // Covers parts of the hours 2 to 4
+        (
+            Utc.ymd(2022, 6, 25).and_hms(2, 30, 0),
+            Utc.ymd(2022, 6, 25).and_hms(4, 15, 0),
+        ),
+        // Starts before the range and covers the hour 0 only
+        (
+            Utc.ymd(2022, 6, 24).and_hms(20, 0, 0),
+            Utc.ymd(2022, 6, 25).and_hms_milli(0, 59, 59, 999),
+        ),
+    ];
+
+    let bitmap = coverage_bitmap(&intervals, &range, Duration::hours(1));
+    assert_eq!(bitmap.len(), 24);
+    let covered_hours: Vec<_> = (0..24).filter(|&hour| bitmap[hour]).collect();
+    assert_eq!(covered_hours, vec![0, 2, 3, 4]);
+
+    // The last slot is truncated at the end of the range
+    assert_eq!(
+        coverage_bitmap(&intervals, &range, Duration::hours(5)),
+        vec![true, false, false, false, false]
+    );
+}